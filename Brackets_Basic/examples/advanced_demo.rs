@@ -124,5 +124,8 @@ fn explain_error(kind: &BracketErrorKind) -> String {
         BracketErrorKind::UnclosedOpenings { expected, .. } => {
             format!("Unclosed bracket (expected '{}')", expected)
         }
+        BracketErrorKind::NestingTooDeep { limit } => {
+            format!("Too deeply nested (limit {})", limit)
+        }
     }
 }
\ No newline at end of file
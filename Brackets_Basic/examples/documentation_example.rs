@@ -34,6 +34,7 @@ fn demonstrate_validation_with_documentation() {
                     BracketErrorKind::UnexpectedClosing { .. } => "UnexpectedClosing",
                     BracketErrorKind::MismatchedPair { .. } => "MismatchedPair",
                     BracketErrorKind::UnclosedOpenings { .. } => "UnclosedOpenings",
+                    BracketErrorKind::NestingTooDeep { .. } => "NestingTooDeep",
                 };
                 println!("❌ '{}' → {} at pos {} ({})", input, error_type, error.index, description);
             }
@@ -71,6 +71,9 @@ fn explain_error(kind: &BracketErrorKind) -> String {
         BracketErrorKind::UnclosedOpenings { expected, open_index } => {
             format!("Unclosed opening bracket at position {}, expected '{}'", open_index, expected)
         }
+        BracketErrorKind::NestingTooDeep { limit } => {
+            format!("Nesting deeper than the stack limit of {}", limit)
+        }
     }
 }
 
@@ -0,0 +1,41 @@
+// Stepping through the validator's stack history
+// Run with: cargo run --example time_travel
+
+use brackets_basic::validate_brackets_with;
+use mission1::recording::diff;
+use mission1::RecordingStack;
+
+fn main() {
+    println!("🕰️  Time-Travel Debugging of Bracket Validation\n");
+
+    let input = "{[(a)](b]}";
+    let mut stack = RecordingStack::new();
+    let result = validate_brackets_with(input, &mut stack);
+    println!("Input: '{}'", input);
+    println!("Result: {:?}\n", result);
+
+    // ==============================================
+    // The full operation log
+    // ==============================================
+    println!("📜 Operation log (seq, op, (bracket, index)):");
+    print!("{}", stack.export());
+
+    // ==============================================
+    // Replay: the stack after every step
+    // ==============================================
+    println!("\n⏪ Stack after each step (top first):");
+    for step in 0..=stack.log().len() {
+        let state = stack.state_at(step);
+        let brackets: String = state.iter().map(|(c, _)| *c).collect();
+        println!("  step {:>2}: [{}]", step, brackets);
+    }
+
+    // ==============================================
+    // Diff: what the failing step changed
+    // ==============================================
+    let steps = stack.log().len();
+    if steps > 0 {
+        let before = stack.state_at(steps - 1);
+        println!("\n🔍 Last step changed: {}", diff(&before, stack.inner()));
+    }
+}
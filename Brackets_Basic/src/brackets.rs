@@ -26,8 +26,9 @@
 //! - **Unexpected Closing**: No matching opener on stack
 //! - **Mismatched Pair**: Wrong closer type for current opener  
 //! - **Unclosed Openings**: Leftover openers at end of input
+//! - **Nesting Too Deep**: A fixed-capacity stack refused an opener ([`validate_brackets_with`] only)
 
-use mission1::Stack;
+use mission1::{Lifo, Stack};
 
 /// Represents the different types of bracket validation errors.
 /// 
@@ -83,6 +84,16 @@ pub enum BracketErrorKind {
     /// - `expected`: The closing bracket that would close this opener
     /// - `open_index`: The byte position where the unclosed opener was found
     UnclosedOpenings { expected: char, open_index: usize },
+
+    /// The bracket stack refused to hold another opening bracket.
+    /// 
+    /// Only produced by [`validate_brackets_with`] when it is given a stack
+    /// with a fixed capacity (e.g. `mission1::ArrayStack` or `BoundedStack`).
+    /// The index points to the opener that did not fit.
+    /// 
+    /// # Fields
+    /// - `limit`: How many brackets were open when the push was refused
+    NestingTooDeep { limit: usize },
 }
 
 /// A bracket validation error with position and type information.
@@ -316,9 +327,55 @@ fn is_closing(c: char) -> bool {
 /// - No string allocation or copying during validation
 pub fn validate_brackets(s: &str) -> Result<(), BracketError> {
     let mut st: Stack<(char, usize)> = Stack::new(); // (expected_closer, open_index)
+    validate_brackets_with(s, &mut st)
+}
+
+/// Validates brackets using a caller-supplied stack backend.
+/// 
+/// Runs exactly the same algorithm as [`validate_brackets`], but over any
+/// [`mission1::Lifo`] implementation, so bounded, inline or instrumented
+/// stacks can be plugged in without forking the algorithm.
+/// 
+/// # Arguments
+/// 
+/// - `s`: The input string to validate
+/// - `st`: Scratch stack of `(expected_closer, open_index)` pairs. It should
+///   start empty and may still hold entries after an error.
+/// 
+/// # Returns
+/// 
+/// - `Ok(())`: If all brackets are properly matched and nested
+/// - `Err(BracketError)`: The first error; a refused push is reported as
+///   [`BracketErrorKind::NestingTooDeep`]
+/// 
+/// # Requirements Traceability
+/// 
+/// - **REQ-2**, **REQ-3**: Same matching rules and error reporting as [`validate_brackets`]
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use brackets_basic::{validate_brackets_with, BracketErrorKind};
+/// use mission1::{ArrayStack, InlineStack};
+/// 
+/// let mut inline: InlineStack<(char, usize), 16> = InlineStack::new();
+/// assert!(validate_brackets_with("{[()]}", &mut inline).is_ok());
+/// 
+/// // A fixed-capacity stack limits nesting depth
+/// let mut fixed: ArrayStack<(char, usize), 2> = ArrayStack::new();
+/// let error = validate_brackets_with("((()))", &mut fixed).unwrap_err();
+/// assert_eq!(error.index, 2);
+/// assert_eq!(error.kind, BracketErrorKind::NestingTooDeep { limit: 2 });
+/// ```
+pub fn validate_brackets_with<S: Lifo<(char, usize)>>(s: &str, st: &mut S) -> Result<(), BracketError> {
     for (i, ch) in s.char_indices() {
         if let Some(expected) = opening_to_expected_closer(ch) {
-            st.push((expected, i));
+            if st.try_push((expected, i)).is_err() {
+                return Err(BracketError {
+                    index: i,
+                    kind: BracketErrorKind::NestingTooDeep { limit: st.len() },
+                });
+            }
         } else if is_closing(ch) {
             match st.pop() {
                 None => {
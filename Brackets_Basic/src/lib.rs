@@ -49,6 +49,9 @@
 //!             BracketErrorKind::UnclosedOpenings { expected, open_index } => {
 //!                 println!("Unclosed bracket at {}, expected '{}'", open_index, expected);
 //!             },
+//!             BracketErrorKind::NestingTooDeep { limit } => {
+//!                 println!("More than {} brackets open at once", limit);
+//!             },
 //!         }
 //!     }
 //! }
@@ -1,5 +1,6 @@
 use std::{fs::File, io::{BufRead, BufReader}};
-use brackets_basic::{validate_brackets, BracketErrorKind};
+use brackets_basic::{validate_brackets, validate_brackets_with, BracketErrorKind};
+use mission1::{ArrayStack, BoundedStack, InlineStack};
 
 fn read_expected_csv(path: &str) -> anyhow::Result<Vec<ExpectedRow>> {
     let f = File::open(path)?;
@@ -93,4 +94,26 @@ fn small_dataset_matches_expected() -> anyhow::Result<()> {
 #[test]
 fn large_dataset_matches_expected() -> anyhow::Result<()> {
     run_file_check("tests/data/brackets_large.txt", "tests/data/brackets_large.expected.csv")
-}
\ No newline at end of file
+}
+#[test]
+fn generic_backends_agree_on_large_dataset() -> anyhow::Result<()> {
+    let reader = BufReader::new(File::open("tests/data/brackets_large.txt")?);
+    for line in reader.lines() {
+        let line = line?;
+        let reference = validate_brackets(&line);
+
+        let mut inline: InlineStack<(char, usize), 8> = InlineStack::new();
+        assert_eq!(validate_brackets_with(&line, &mut inline), reference);
+
+        let mut bounded = BoundedStack::with_capacity(line.len().max(1));
+        assert_eq!(validate_brackets_with(&line, &mut bounded), reference);
+
+        // A tiny fixed stack either agrees or reports the nesting limit
+        let mut fixed: ArrayStack<(char, usize), 2> = ArrayStack::new();
+        match validate_brackets_with(&line, &mut fixed) {
+            Err(e) if e.kind == (BracketErrorKind::NestingTooDeep { limit: 2 }) => {}
+            other => assert_eq!(other, reference),
+        }
+    }
+    Ok(())
+}
@@ -24,9 +24,19 @@
 //! let errors = validate_with_options(")](", &opts).unwrap_err();
 //! assert!(errors.len() > 1);
 //! ```
+//!
+//! Everything up to [`validate_indexed_iter_report`] only needs `core`; the
+//! APIs that collect errors into a `Vec` require the default `std` feature.
+
+use mission1::Lifo;
+#[cfg(feature = "std")]
+use mission1::{ArrayStack, Stack};
 
-use mission1::Stack;
-use std::collections::HashMap;
+/// Maximum number of bracket pairs an [`Alphabet`] can hold.
+///
+/// The pairs live in a fixed table inside the alphabet, so building and
+/// querying one never allocates.
+pub const MAX_PAIRS: usize = 16;
 
 /// Defines the set of valid bracket pairs for validation.
 ///
@@ -65,13 +75,15 @@ use std::collections::HashMap;
 ///
 /// # Performance
 ///
-/// - `is_opener()`: O(1) hash table lookup
-/// - `is_closer()`: O(n) where n is the number of bracket pairs
-/// - `expected_for()`: O(1) hash table lookup
+/// - `is_opener()`: O(n) scan of at most [`MAX_PAIRS`] pairs
+/// - `is_closer()`: O(n) scan of at most [`MAX_PAIRS`] pairs
+/// - `expected_for()`: O(n) scan of at most [`MAX_PAIRS`] pairs
 #[derive(Clone, Debug)]
 pub struct Alphabet { 
-    /// Mapping from opening bracket characters to their corresponding closing characters
-    pub open_to_close: HashMap<char, char> 
+    /// Opening/closing bracket pairs; only the first `len` entries are in use
+    pairs: [(char, char); MAX_PAIRS],
+    /// Number of pairs in use
+    len: usize,
 }
 
 impl Alphabet {
@@ -103,10 +115,37 @@ impl Alphabet {
     ///     ('⌊', '⌋'),  // Floor brackets
     /// ]);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pairs` contains more than [`MAX_PAIRS`] distinct openers.
+    /// If an opener appears twice, the later closer wins.
     pub fn with_pairs(pairs: &[(char, char)]) -> Self {
-        let mut m = HashMap::new();
-        for &(o, c) in pairs { m.insert(o, c); }
-        Self { open_to_close: m }
+        let mut alphabet = Self { pairs: [('\0', '\0'); MAX_PAIRS], len: 0 };
+        for &(o, c) in pairs {
+            if let Some(slot) = alphabet.pairs[..alphabet.len].iter_mut().find(|(open, _)| *open == o) {
+                slot.1 = c;
+                continue;
+            }
+            assert!(alphabet.len < MAX_PAIRS, "an Alphabet holds at most {} bracket pairs", MAX_PAIRS);
+            alphabet.pairs[alphabet.len] = (o, c);
+            alphabet.len += 1;
+        }
+        alphabet
+    }
+
+    /// Returns the `(opener, closer)` pairs of this alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brackets_extended::Alphabet;
+    ///
+    /// let alphabet = Alphabet::with_pairs(&[('<', '>')]);
+    /// assert_eq!(alphabet.pairs(), &[('<', '>')]);
+    /// ```
+    pub fn pairs(&self) -> &[(char, char)] {
+        &self.pairs[..self.len]
     }
     
     /// Creates the default ASCII alphabet with standard programming brackets.
@@ -140,7 +179,8 @@ impl Alphabet {
     ///
     /// # Performance
     ///
-    /// This operation is O(1) using hash table lookup.
+    /// This operation is O(n) where n is the number of bracket pairs
+    /// (at most [`MAX_PAIRS`]).
     ///
     /// # Examples
     ///
@@ -155,7 +195,7 @@ impl Alphabet {
     /// ```
     #[inline] 
     pub fn is_opener(&self, ch: char) -> bool { 
-        self.open_to_close.contains_key(&ch) 
+        self.pairs().iter().any(|&(o, _)| o == ch) 
     }
     
     /// Checks if a character is a closing bracket in this alphabet.
@@ -178,7 +218,7 @@ impl Alphabet {
     /// ```
     #[inline] 
     pub fn is_closer(&self, ch: char) -> bool { 
-        self.open_to_close.values().any(|&v| v == ch) 
+        self.pairs().iter().any(|&(_, c)| c == ch) 
     }
     
     /// Returns the expected closing character for a given opening bracket.
@@ -194,7 +234,8 @@ impl Alphabet {
     ///
     /// # Performance
     ///
-    /// This operation is O(1) using hash table lookup.
+    /// This operation is O(n) where n is the number of bracket pairs
+    /// (at most [`MAX_PAIRS`]).
     ///
     /// # Examples
     ///
@@ -210,7 +251,7 @@ impl Alphabet {
     /// ```
     #[inline] 
     pub fn expected_for(&self, opener: char) -> Option<char> { 
-        self.open_to_close.get(&opener).copied() 
+        self.pairs().iter().find(|&&(o, _)| o == opener).map(|&(_, c)| c) 
     }
 }
 
@@ -293,6 +334,21 @@ pub enum BracketErrorKind {
         /// The position in the input where the unclosed opening bracket appeared
         open_index: usize 
     },
+
+    /// An opening bracket would exceed the fixed nesting capacity.
+    ///
+    /// Only produced when the bracket stack refuses a push, i.e. by
+    /// [`validate_indexed_iter_fixed`] (an allocation-free [`mission1::ArrayStack`]) or by
+    /// [`validate_indexed_iter_with`] given a bounded stack. Validation stops
+    /// at this point because the remaining nesting can no longer be tracked.
+    ///
+    /// # Fields
+    ///
+    /// * `limit` - The maximum nesting depth that was configured
+    NestingTooDeep {
+        /// The maximum number of simultaneously open brackets
+        limit: usize
+    },
 }
 
 /// A bracket validation error with position information.
@@ -512,60 +568,186 @@ impl Default for Options {
 /// let result = validate_indexed_iter(indexed_chars, &opts);
 /// assert!(result.is_err()); // Mismatched pair: ( and ]
 /// ```
+#[cfg(feature = "std")]
 pub fn validate_indexed_iter<I>(iter: I, opts: &Options) -> Result<(), Vec<BracketError>>
 where
     I: IntoIterator<Item = (usize, char)>,
 {
     let mut st: Stack<(char, usize)> = Stack::new(); // (expected_closer, open_idx)
+    validate_indexed_iter_with(iter, opts, &mut st)
+}
+
+/// Fixed-capacity variant of [`validate_indexed_iter`] that never allocates
+/// for its bracket stack.
+///
+/// Open brackets are tracked in a [`mission1::ArrayStack`] holding at most `N`
+/// entries, which is the same stack `mission1` provides in `no_std` builds.
+/// Memory use is therefore fixed at compile time, which suits firmware and
+/// hostile input alike.
+///
+/// # Arguments
+///
+/// * `N` - Maximum nesting depth (number of simultaneously open brackets)
+/// * `iter` - An iterator yielding `(index, char)` pairs
+/// * `opts` - Configuration options controlling validation behavior
+///
+/// # Returns
+///
+/// * `Ok(())` - If all brackets are properly matched and nested
+/// * `Err(Vec<BracketError>)` - Validation errors; if the nesting exceeds `N`,
+///   the last error is [`BracketErrorKind::NestingTooDeep`] and validation
+///   stops there, even in [`ErrorMode::CollectAll`]
+///
+/// # Examples
+///
+/// ```rust
+/// use brackets_extended::{validate_indexed_iter_fixed, BracketErrorKind, Options};
+///
+/// let opts = Options::default();
+/// assert!(validate_indexed_iter_fixed::<4, _>("([{}])".char_indices(), &opts).is_ok());
+///
+/// let errors = validate_indexed_iter_fixed::<2, _>("((()))".char_indices(), &opts).unwrap_err();
+/// assert_eq!(errors[0].index, 2);
+/// assert_eq!(errors[0].kind, BracketErrorKind::NestingTooDeep { limit: 2 });
+/// ```
+#[cfg(feature = "std")]
+pub fn validate_indexed_iter_fixed<const N: usize, I>(iter: I, opts: &Options) -> Result<(), Vec<BracketError>>
+where
+    I: IntoIterator<Item = (usize, char)>,
+{
+    let mut st: ArrayStack<(char, usize), N> = ArrayStack::new();
+    validate_indexed_iter_with(iter, opts, &mut st)
+}
+
+/// Validates `(index, char)` pairs using a caller-supplied bracket stack.
+///
+/// This is the algorithm behind [`validate_indexed_iter`] and
+/// [`validate_indexed_iter_fixed`], generic over any [`mission1::Lifo`]
+/// backend so bounded, inline or instrumented stacks can be plugged in without
+/// forking it. Each entry pushed is `(expected_closer, open_index)`.
+///
+/// # Arguments
+///
+/// * `iter` - An iterator yielding `(index, char)` pairs
+/// * `opts` - Configuration options controlling validation behavior
+/// * `stack` - Scratch stack for open brackets; it should start empty and may
+///   still hold frames when validation returns early
+///
+/// # Returns
+///
+/// * `Ok(())` - If all brackets are properly matched and nested
+/// * `Err(Vec<BracketError>)` - Validation errors; a refused push ends
+///   validation with [`BracketErrorKind::NestingTooDeep`]
+///
+/// # Examples
+///
+/// ```rust
+/// use brackets_extended::{validate_indexed_iter_with, BracketErrorKind, Options};
+/// use mission1::{BoundedStack, InlineStack};
+///
+/// let opts = Options::default();
+///
+/// let mut inline: InlineStack<(char, usize), 8> = InlineStack::new();
+/// assert!(validate_indexed_iter_with("{[()]}".char_indices(), &opts, &mut inline).is_ok());
+///
+/// let mut bounded = BoundedStack::with_capacity(1);
+/// let errors = validate_indexed_iter_with("([])".char_indices(), &opts, &mut bounded).unwrap_err();
+/// assert_eq!(errors[0].kind, BracketErrorKind::NestingTooDeep { limit: 1 });
+/// ```
+#[cfg(feature = "std")]
+pub fn validate_indexed_iter_with<I, S>(iter: I, opts: &Options, st: &mut S) -> Result<(), Vec<BracketError>>
+where
+    I: IntoIterator<Item = (usize, char)>,
+    S: Lifo<(char, usize)>,
+{
     let mut errors: Vec<BracketError> = Vec::new();
+    validate_indexed_iter_report(iter, opts, st, |e| errors.push(e));
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Allocation-free validation engine that hands each error to a callback.
+///
+/// Only depends on `core`, so it is available without the `std` feature and
+/// suits microcontroller firmware: pair it with a [`mission1::ArrayStack`]
+/// and store the errors wherever the caller likes (a fixed array, a counter,
+/// a UART). [`validate_indexed_iter_with`] is this function collecting into
+/// a `Vec`.
+///
+/// # Arguments
+///
+/// * `iter` - An iterator yielding `(index, char)` pairs
+/// * `opts` - Configuration options controlling validation behavior
+/// * `stack` - Scratch stack for open brackets; it should start empty and may
+///   still hold frames when validation returns early
+/// * `report` - Called once per error, in input order
+///
+/// # Returns
+///
+/// The number of errors reported; `0` means the input is valid. With
+/// [`ErrorMode::StopAtFirst`] this is at most 1, and a refused push always
+/// ends validation with [`BracketErrorKind::NestingTooDeep`].
+///
+/// # Examples
+///
+/// ```rust
+/// use brackets_extended::{validate_indexed_iter_report, BracketError, BracketErrorKind, ErrorMode, Options};
+/// use mission1::ArrayStack;
+///
+/// let opts = Options { error_mode: ErrorMode::CollectAll, ..Options::default() };
+/// let mut stack: ArrayStack<(char, usize), 4> = ArrayStack::new();
+///
+/// // Keep the first two errors in a fixed buffer; count the rest
+/// let mut first: [Option<BracketError>; 2] = [None, None];
+/// let found = validate_indexed_iter_report(")](".char_indices(), &opts, &mut stack, |e| {
+///     if let Some(slot) = first.iter_mut().find(|slot| slot.is_none()) {
+///         *slot = Some(e);
+///     }
+/// });
+/// assert_eq!(found, 3);
+/// assert_eq!(first[0].as_ref().unwrap().kind, BracketErrorKind::UnexpectedClosing { found: ')' });
+/// ```
+pub fn validate_indexed_iter_report<I, S, F>(iter: I, opts: &Options, st: &mut S, mut report: F) -> usize
+where
+    I: IntoIterator<Item = (usize, char)>,
+    S: Lifo<(char, usize)>,
+    F: FnMut(BracketError),
+{
+    let stop_at_first = matches!(opts.error_mode, ErrorMode::StopAtFirst);
     let alph = &opts.alphabet;
+    let mut count = 0;
 
     for (i, ch) in iter {
         if alph.is_opener(ch) {
             let expected = alph.expected_for(ch).expect("alphabet inconsistent");
-            st.push((expected, i));
-        } else if alph.is_closer(ch) {
-            match st.pop() {
-                None => {
-                    let e = BracketError { index: i, kind: BracketErrorKind::UnexpectedClosing { found: ch } };
-                    if matches!(opts.error_mode, ErrorMode::StopAtFirst) { return Err(vec![e]); }
-                    errors.push(e);
-                }
-                Some((expected, _open_idx)) => {
-                    if ch != expected {
-                        let e = BracketError { index: i, kind: BracketErrorKind::MismatchedPair { expected, found: ch } };
-                        if matches!(opts.error_mode, ErrorMode::StopAtFirst) { return Err(vec![e]); }
-                        errors.push(e);
-                    }
-                }
+            if st.try_push((expected, i)).is_err() {
+                // Capacity exhausted: later closers could no longer be matched reliably
+                report(BracketError { index: i, kind: BracketErrorKind::NestingTooDeep { limit: st.len() } });
+                return count + 1;
             }
+        } else if alph.is_closer(ch) {
+            let kind = match st.pop() {
+                None => BracketErrorKind::UnexpectedClosing { found: ch },
+                Some((expected, _open_idx)) if ch != expected => BracketErrorKind::MismatchedPair { expected, found: ch },
+                Some(_) => continue,
+            };
+            report(BracketError { index: i, kind });
+            count += 1;
+            if stop_at_first { return count; }
         } else {
             // ignore
         }
     }
 
-    if !st.is_empty() {
-        match opts.unclosed_policy {
-            UnclosedPolicy::LatestOpen => {
-                if let Some((expected, open_idx)) = st.pop() {
-                    let e = BracketError { index: open_idx, kind: BracketErrorKind::UnclosedOpenings { expected, open_index: open_idx } };
-                    if matches!(opts.error_mode, ErrorMode::StopAtFirst) { return Err(vec![e]); }
-                    errors.push(e);
-                }
-            }
-            UnclosedPolicy::EarliestOpen => {
-                let mut tmp: Vec<(char, usize)> = Vec::new();
-                while let Some(x) = st.pop() { tmp.push(x); }
-                if let Some(&(expected, open_idx)) = tmp.last() {
-                    let e = BracketError { index: open_idx, kind: BracketErrorKind::UnclosedOpenings { expected, open_index: open_idx } };
-                    if matches!(opts.error_mode, ErrorMode::StopAtFirst) { return Err(vec![e]); }
-                    errors.push(e);
-                }
-            }
-        }
+    let unclosed = match opts.unclosed_policy {
+        UnclosedPolicy::LatestOpen => st.pop(),
+        // The earliest opener sits at the bottom; read it without unwinding
+        UnclosedPolicy::EarliestOpen => st.bottom().copied(),
+    };
+    if let Some((expected, open_idx)) = unclosed {
+        report(BracketError { index: open_idx, kind: BracketErrorKind::UnclosedOpenings { expected, open_index: open_idx } });
+        count += 1;
     }
-
-    if errors.is_empty() { Ok(()) } else { Err(errors) }
+    count
 }
 
 /// Traditional bracket validation API with single error reporting.
@@ -618,6 +800,7 @@ where
 /// - Legacy code integration
 /// - Performance-critical applications (stops at first error)
 /// - Quick validation checks
+#[cfg(feature = "std")]
 pub fn validate_brackets(s: &str) -> Result<(), BracketError> {
     match validate_with_options(s, &Options::default()) {
         Ok(()) => Ok(()),
@@ -682,6 +865,7 @@ pub fn validate_brackets(s: &str) -> Result<(), BracketError> {
 /// - Educational tools showing all bracket errors
 /// - Configuration file validation
 /// - Custom markup language validation
+#[cfg(feature = "std")]
 pub fn validate_with_options(s: &str, opts: &Options) -> Result<(), Vec<BracketError>> {
     let mut idx = 0usize;
     let iter = s.chars().map(move |ch| {
@@ -745,6 +929,7 @@ pub fn validate_with_options(s: &str, opts: &Options) -> Result<(), Vec<BracketE
 /// - Filtering input before validation
 /// - Character-based error reporting
 /// - Working with character-oriented data structures
+#[cfg(feature = "std")]
 pub fn validate_iter<I>(iter: I, opts: &Options) -> Result<(), Vec<BracketError>>
 where
     I: IntoIterator<Item = char>,
@@ -813,6 +998,7 @@ where
 /// - Custom position tracking (line/column, custom offsets)
 /// - Integration with existing parsing infrastructure
 /// - Performance optimization when positions are already available
+#[cfg(feature = "std")]
 pub fn validate_indexed<I>(iter: I, opts: &Options) -> Result<(), Vec<BracketError>>
 where
     I: IntoIterator<Item = (usize, char)>,
@@ -121,9 +121,20 @@
 //! 1. **Alphabet**: Defines which characters are brackets and their pairs
 //! 2. **Options**: Configures validation behavior (alphabet, error mode, policies)
 //! 3. **Error Types**: Detailed error information with positions and context
+//!
+//! ## `no_std` Support
+//!
+//! The `std` feature is on by default. Without it (`--no-default-features`)
+//! the crate is `no_std` and allocation-free: alphabets use a fixed pair table
+//! and [`validate_indexed_iter_report`] hands errors to a callback instead of
+//! collecting a `Vec`, so it can run in microcontroller firmware with a
+//! `mission1::ArrayStack`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 pub mod brackets;
 
 // Use Mission1's stack instead of our own duplicate
+#[cfg(feature = "std")]
 pub use mission1::Stack;
 pub use brackets::*;
\ No newline at end of file
@@ -54,3 +54,37 @@ fn iterator_api_indexed_preserves_byte_indices() {
     assert!(matches!(errs[0].kind, BracketErrorKind::UnexpectedClosing { found: ')' }));
     assert_eq!(errs[0].index, 2);
 }
+
+#[test]
+fn fixed_capacity_matches_heap_validator() {
+    let opts = Options::default();
+    for s in ["", "([]{})", "(]", ")(", "((("] {
+        assert_eq!(
+            validate_indexed_iter_fixed::<8, _>(s.char_indices(), &opts),
+            validate_indexed_iter(s.char_indices(), &opts),
+            "input {:?}", s
+        );
+    }
+}
+
+#[test]
+fn fixed_capacity_reports_nesting_too_deep() {
+    let opts = Options { error_mode: ErrorMode::CollectAll, ..Options::default() };
+    // Exactly at the limit is fine
+    assert!(validate_indexed_iter_fixed::<3, _>("((()))".char_indices(), &opts).is_ok());
+
+    let errs = validate_indexed_iter_fixed::<3, _>("]((((".char_indices(), &opts).unwrap_err();
+    assert!(matches!(errs[0].kind, BracketErrorKind::UnexpectedClosing { .. }));
+    assert_eq!(errs.last().unwrap().kind, BracketErrorKind::NestingTooDeep { limit: 3 });
+    assert_eq!(errs.last().unwrap().index, 4);
+}
+
+#[test]
+fn earliest_open_leaves_caller_stack_intact() {
+    let opts = Options { unclosed_policy: UnclosedPolicy::EarliestOpen, ..Options::default() };
+    let mut st: mission1::Stack<(char, usize)> = mission1::Stack::new();
+    let errs = validate_indexed_iter_with("([{".char_indices(), &opts, &mut st).unwrap_err();
+    assert_eq!(errs[0].index, 0);
+    assert_eq!(st.len(), 3);
+    assert_eq!(st.peek(), Some(&('}', 2)));
+}
@@ -0,0 +1,59 @@
+// Exercises only the API that is available with `--no-default-features`,
+// so this file is compiled and run in both feature configurations.
+
+use brackets_extended::{
+    validate_indexed_iter_report, Alphabet, BracketError, BracketErrorKind, ErrorMode, Options,
+    UnclosedPolicy, MAX_PAIRS,
+};
+use mission1::ArrayStack;
+
+/// Validates `s`, keeping up to four errors in a fixed buffer like firmware would
+fn report(s: &str, opts: &Options) -> (usize, [Option<BracketError>; 4]) {
+    let mut stack: ArrayStack<(char, usize), 8> = ArrayStack::new();
+    let mut errors: [Option<BracketError>; 4] = Default::default();
+    let mut stored = 0;
+    let found = validate_indexed_iter_report(s.char_indices(), opts, &mut stack, |e| {
+        if stored < errors.len() {
+            errors[stored] = Some(e);
+            stored += 1;
+        }
+    });
+    (found, errors)
+}
+
+#[test]
+fn fixed_buffers_report_errors_in_order() {
+    let opts = Options { error_mode: ErrorMode::CollectAll, ..Options::default() };
+    assert_eq!(report("([{}])", &opts).0, 0);
+
+    let (found, errors) = report(")(]", &opts);
+    assert_eq!(found, 2);
+    assert_eq!(errors[0], Some(BracketError { index: 0, kind: BracketErrorKind::UnexpectedClosing { found: ')' } }));
+    assert_eq!(errors[1], Some(BracketError { index: 2, kind: BracketErrorKind::MismatchedPair { expected: ')', found: ']' } }));
+    assert_eq!(errors[2], None);
+
+    let first = Options { unclosed_policy: UnclosedPolicy::EarliestOpen, ..Options::default() };
+    let (found, errors) = report("a(b[c", &first);
+    assert_eq!(found, 1);
+    assert_eq!(errors[0].as_ref().unwrap().index, 1);
+}
+
+#[test]
+fn nesting_beyond_the_array_stack_stops_validation() {
+    let opts = Options { error_mode: ErrorMode::CollectAll, ..Options::default() };
+    let (found, errors) = report("((((((((((", &opts);
+    assert_eq!(found, 1);
+    assert_eq!(errors[0].as_ref().unwrap().kind, BracketErrorKind::NestingTooDeep { limit: 8 });
+}
+
+#[test]
+fn alphabet_uses_a_fixed_pair_table() {
+    let alphabet = Alphabet::with_pairs(&[('<', '>'), ('(', ')'), ('<', ']')]);
+    assert_eq!(alphabet.pairs(), &[('<', ']'), ('(', ')')]); // Later closer wins
+    assert_eq!(alphabet.expected_for('<'), Some(']'));
+    assert!(alphabet.is_closer(')') && !alphabet.is_closer('>'));
+
+    let many: Vec<(char, char)> = (0..=MAX_PAIRS as u32).map(|i| (char::from_u32(0x100 + i).unwrap(), '!')).collect();
+    assert_eq!(Alphabet::with_pairs(&many[..MAX_PAIRS]).pairs().len(), MAX_PAIRS);
+    assert!(std::panic::catch_unwind(|| Alphabet::with_pairs(&many)).is_err());
+}
@@ -1,3 +1,4 @@
+use mission1::frames::FrameStack;
 use mission1::Stack;
 
 fn demonstrate_exclusive_access_concept() {
@@ -118,8 +119,43 @@ fn show_contrast_with_multiple_immutable() {
     println!("Added new data after readers finished: {:?}", stack);
 }
 
+fn demonstrate_call_frames() {
+    println!("\n=== EXCLUSIVE ACCESS PER CALL FRAME ===");
+    
+    // An interpreter gives every function call its own frame: local slots
+    // plus a private operand stack. Only the top frame is reachable.
+    let mut vm = FrameStack::with_max_depth(3);
+    vm.push_frame("main", 1).unwrap();
+    vm.set_local(0, 40).unwrap();
+    vm.push(1).unwrap();
+    vm.push(2).unwrap();
+    
+    // add(a, b): the two arguments MOVE from main's operands into add's locals
+    vm.call("add", 2, 2).unwrap();
+    println!("Inside add: locals = {:?}", vm.current().unwrap().locals());
+    
+    // The callee cannot pop what belongs to main - its own stack is empty
+    match vm.pop() {
+        Err(err) => print!("Popping in add fails with {}", err),
+        Ok(v) => println!("Unexpected value {}", v),
+    }
+    
+    let sum = vm.local(0).unwrap() + vm.local(1).unwrap();
+    vm.push(sum).unwrap();
+    vm.ret().unwrap(); // The result moves back to main
+    println!("Back in main: operands = {:?}, local 0 = {}", vm.peek(), vm.local(0).unwrap());
+    
+    // Recursion is bounded by the configured depth
+    vm.push_frame("f", 0).unwrap();
+    vm.push_frame("f", 0).unwrap();
+    if let Err(err) = vm.push_frame("f", 0) {
+        print!("Third nested call fails with {}", err);
+    }
+}
+
 fn main() {
     demonstrate_exclusive_access_concept();
     demonstrate_why_exclusive_access_matters();
     show_contrast_with_multiple_immutable();
+    demonstrate_call_frames();
 }
\ No newline at end of file
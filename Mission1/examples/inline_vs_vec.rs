@@ -0,0 +1,72 @@
+// Benchmark: InlineStack vs Vec-backed Stack on bracket-like workloads
+// Run with: cargo run --release --example inline_vs_vec
+
+use mission1::{InlineStack, Stack};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ROUNDS: usize = 1_000_000;
+
+fn main() {
+    println!("⚡ InlineStack vs Stack Benchmark\n");
+
+    // ==============================================
+    // Test 1: Shallow nesting (fits inline)
+    // ==============================================
+    println!("📊 Test 1: Short-lived stacks, nesting depth 6 (fits in N = 16)");
+    compare(6);
+
+    // ==============================================
+    // Test 2: Nesting right at the inline capacity
+    // ==============================================
+    println!("\n📊 Test 2: Nesting depth 16 (exactly N = 16)");
+    compare(16);
+
+    // ==============================================
+    // Test 3: Deep nesting (spills to the heap)
+    // ==============================================
+    println!("\n📊 Test 3: Nesting depth 64 (spills past N = 16)");
+    compare(64);
+
+    println!("\n💾 Struct sizes:");
+    println!("  Stack<(char, usize)>:            {} bytes", std::mem::size_of::<Stack<(char, usize)>>());
+    println!("  InlineStack<(char, usize), 16>:  {} bytes", std::mem::size_of::<InlineStack<(char, usize), 16>>());
+
+    println!("\n✅ InlineStack should win clearly while nesting fits inline (no allocation per stack)");
+    println!("✅ Once it spills, both pay for a heap allocation and timings converge");
+    println!("📝 For production code, use Criterion crate for precise benchmarks");
+}
+
+/// Simulates validating one line: push `depth` openers, then pop them all
+fn compare(depth: usize) {
+    let start = Instant::now();
+    for round in 0..ROUNDS {
+        let mut stack = Stack::new();
+        for i in 0..depth {
+            stack.push(black_box(('(', round + i)));
+        }
+        while let Some(frame) = stack.pop() {
+            black_box(frame);
+        }
+    }
+    let vec_time = start.elapsed();
+
+    let start = Instant::now();
+    for round in 0..ROUNDS {
+        let mut stack: InlineStack<(char, usize), 16> = InlineStack::new();
+        for i in 0..depth {
+            stack.push(black_box(('(', round + i)));
+        }
+        while let Some(frame) = stack.pop() {
+            black_box(frame);
+        }
+    }
+    let inline_time = start.elapsed();
+
+    println!("  Stack (Vec):        {} rounds took {:?}", ROUNDS, vec_time);
+    println!("  InlineStack<_, 16>: {} rounds took {:?}", ROUNDS, inline_time);
+    println!(
+        "  Speedup: {:.2}x",
+        vec_time.as_secs_f64() / inline_time.as_secs_f64()
+    );
+}
@@ -1,7 +1,7 @@
 // Performance Validation (Simple Version)
 // Run with: cargo run --example performance_check
 
-use mission1::Stack;
+use mission1::{InstrumentedStack, Stack};
 use std::time::Instant;
 
 fn main() {
@@ -76,6 +76,31 @@ fn main() {
              std::mem::size_of_val(&stack));
     println!("✅ Stack struct itself is small (just holds a Vec)");
     
+    // ==============================================
+    // Test 4: Allocation metrics from the inside
+    // ==============================================
+    println!("\n🔬 Test 4: Allocation Metrics (InstrumentedStack)");
+
+    let mut stack = InstrumentedStack::new();
+    for depth in [10, 500, 40] {
+        for i in 0..depth {
+            stack.push(i);
+        }
+        while stack.pop().is_some() {}
+    }
+    let stats = *stack.stats();
+    println!("  Default stack:   {:?}", stats);
+
+    let mut tuned = InstrumentedStack::with_capacity(stats.peak_len);
+    for depth in [10, 500, 40] {
+        for i in 0..depth {
+            tuned.push(i);
+        }
+        while tuned.pop().is_some() {}
+    }
+    println!("  with_capacity({}): {:?}", stats.peak_len, tuned.stats());
+    println!("✅ Using peak_len as the capacity hint should remove every reallocation");
+
     println!("\n🏁 Performance validation complete!");
     println!("📝 For production code, use Criterion crate for precise benchmarks");
 }
\ No newline at end of file
@@ -0,0 +1,202 @@
+// A stack that tracks a running aggregate (min, max, sum, gcd, ...) in O(1)
+//
+// Each slot stores the pushed value together with the aggregate of everything
+// at or below it. Popping simply discards the top slot, which leaves the slot
+// below - and therefore the correct aggregate for the remaining elements - on top.
+// This works for ANY associative operation; no inverse (like subtraction) is needed.
+//
+// REQ-A1: The aggregate of all elements shall be queryable in O(1) after every push and pop
+// REQ-A2: The combining operation shall be user-supplied (any associative fn)
+// REQ-A3: Storage shall be built on mission1::Stack, keeping its ownership semantics
+
+use crate::Stack;
+use std::fmt;
+
+/// A LIFO stack that maintains `op(bottom, ..., top)` for its current contents
+///
+/// # Type Parameters
+/// * `T` - Element type; `Clone` because each slot keeps its own copy of the running aggregate
+/// * `F` - The associative combining operation, called as `op(&below, &new)`
+///
+/// # Examples
+/// ```
+/// use mission1::AggregateStack;
+///
+/// let mut mins = AggregateStack::min();
+/// mins.push(5);
+/// mins.push(2);
+/// mins.push(7);
+/// assert_eq!(mins.aggregate(), Some(&2));
+///
+/// mins.pop(); // 7
+/// mins.pop(); // 2
+/// assert_eq!(mins.aggregate(), Some(&5)); // Previous minimum restored in O(1)
+///
+/// // Any associative operation works, e.g. gcd
+/// fn gcd(a: &u64, b: &u64) -> u64 {
+///     let (mut a, mut b) = (*a, *b);
+///     while b != 0 { (a, b) = (b, a % b); }
+///     a
+/// }
+/// let mut g = AggregateStack::new(gcd);
+/// g.push(12);
+/// g.push(18);
+/// assert_eq!(g.aggregate(), Some(&6));
+/// ```
+pub struct AggregateStack<T, F> {
+    /// (value, aggregate of this value and everything below it)
+    slots: Stack<(T, T)>,
+    op: F,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> AggregateStack<T, F> {
+    /// Creates an empty stack that combines elements with `op` (REQ-A2)
+    ///
+    /// `op` must be associative: `op(a, op(b, c)) == op(op(a, b), c)`.
+    pub fn new(op: F) -> Self {
+        Self { slots: Stack::new(), op }
+    }
+
+    /// Pushes a value and extends the running aggregate (O(1) plus one `op` call)
+    pub fn push(&mut self, x: T) {
+        let agg = match self.slots.peek() {
+            Some((_, below)) => (self.op)(below, &x),
+            None => x.clone(),
+        };
+        self.slots.push((x, agg));
+    }
+
+    /// Pops and returns the top value; the aggregate reverts to that of the
+    /// remaining elements (REQ-A1, REQ-A3: ownership is transferred)
+    pub fn pop(&mut self) -> Option<T> {
+        self.slots.pop().map(|(x, _)| x)
+    }
+
+    /// Returns the top value without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.slots.peek().map(|(x, _)| x)
+    }
+
+    /// Returns the aggregate of every element currently on the stack (REQ-A1: O(1))
+    ///
+    /// `None` when the stack is empty, since there is no identity element in general.
+    pub fn aggregate(&self) -> Option<&T> {
+        self.slots.peek().map(|(_, agg)| agg)
+    }
+
+    /// Returns the number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns true if the stack contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns an iterator over the values from top to bottom
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.slots.iter().map(|(x, _)| x)
+    }
+}
+
+impl<T: Clone + Ord> AggregateStack<T, fn(&T, &T) -> T> {
+    /// Creates a stack tracking the minimum element
+    pub fn min() -> Self {
+        Self::new(|a, b| a.min(b).clone())
+    }
+
+    /// Creates a stack tracking the maximum element
+    pub fn max() -> Self {
+        Self::new(|a, b| a.max(b).clone())
+    }
+}
+
+impl<T> AggregateStack<T, fn(&T, &T) -> T>
+where
+    T: Clone + for<'a> std::ops::Add<&'a T, Output = T>,
+{
+    /// Creates a stack tracking the sum of its elements
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::AggregateStack;
+    /// let mut s = AggregateStack::sum();
+    /// s.push(1);
+    /// s.push(2);
+    /// s.push(3);
+    /// assert_eq!(s.aggregate(), Some(&6));
+    /// ```
+    pub fn sum() -> Self {
+        Self::new(|a, b| a.clone() + b)
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for AggregateStack<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AggregateStack")
+            .field("values", &self.slots.iter().map(|(x, _)| x).collect::<Vec<_>>())
+            .field("aggregate", &self.slots.peek().map(|(_, agg)| agg))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test] // REQ-A1
+    fn min_and_max_follow_push_and_pop() {
+        let mut mins = AggregateStack::min();
+        let mut maxs = AggregateStack::max();
+        let input = [4, 7, 1, 9, 1, 3];
+        for &x in &input {
+            mins.push(x);
+            maxs.push(x);
+        }
+        for k in (1..=input.len()).rev() {
+            assert_eq!(mins.aggregate(), input[..k].iter().min());
+            assert_eq!(maxs.aggregate(), input[..k].iter().max());
+            mins.pop();
+            maxs.pop();
+        }
+        assert_eq!(mins.aggregate(), None);
+        assert!(maxs.is_empty());
+    }
+
+    #[test] // REQ-A1, REQ-A2: sliding-window style interleaving against a brute-force model
+    fn matches_recomputation_for_custom_op() {
+        let gcd = |a: &u64, b: &u64| {
+            let (mut a, mut b) = (*a, *b);
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        let mut s = AggregateStack::new(gcd);
+        let mut model: Vec<u64> = Vec::new();
+        for i in 0..500u64 {
+            if i % 5 == 3 {
+                assert_eq!(s.pop(), model.pop());
+            } else {
+                let x = (i * 36) % 97 + 1;
+                s.push(x * 6);
+                model.push(x * 6);
+            }
+            let expected = model.iter().copied().reduce(|a, b| gcd(&a, &b));
+            assert_eq!(s.aggregate().copied(), expected);
+        }
+    }
+
+    #[test] // REQ-A2, REQ-A3
+    fn sum_of_owned_values() {
+        let mut s = AggregateStack::sum();
+        s.push(1.5f64);
+        s.push(2.25);
+        assert_eq!(s.aggregate(), Some(&3.75));
+        assert_eq!(s.peek(), Some(&2.25));
+        assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![2.25, 1.5]);
+        assert_eq!(s.pop(), Some(2.25));
+        assert_eq!(s.aggregate(), Some(&1.5));
+    }
+}
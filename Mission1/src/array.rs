@@ -0,0 +1,214 @@
+// A fixed-capacity, array-backed stack that never allocates
+//
+// This is the stack used by `no_std` builds (e.g. microcontroller firmware):
+// all storage lives in a `[MaybeUninit<T>; N]` inside the struct, so it works
+// without an allocator and its memory footprint is known at compile time.
+// It only depends on `core`, so it is available with or without the `std` feature.
+//
+// REQ-F1: The stack shall hold at most N elements in inline array storage, never allocating
+// REQ-F2: try_push shall hand the value back as Err(x) when full (backpressure)
+// REQ-F3: The stack shall build without `std` (`--no-default-features`)
+
+use core::fmt;
+use core::mem::MaybeUninit;
+
+/// A LIFO stack with compile-time capacity `N` and no heap usage
+///
+/// # Type Parameters
+/// * `T` - The type of elements stored in the stack
+/// * `N` - Maximum number of elements (chosen at compile time)
+///
+/// # Examples
+/// ```
+/// use mission1::ArrayStack;
+///
+/// let mut stack: ArrayStack<char, 2> = ArrayStack::new();
+/// assert!(stack.try_push('(').is_ok());
+/// assert!(stack.try_push('[').is_ok());
+/// assert_eq!(stack.try_push('{'), Err('{')); // Full - value handed back
+///
+/// assert_eq!(stack.pop(), Some('['));
+/// assert_eq!(stack.pop(), Some('('));
+/// assert_eq!(stack.pop(), None);
+/// ```
+pub struct ArrayStack<T, const N: usize> {
+    /// Slots `0..len` are initialized
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayStack<T, N> {
+    /// Creates an empty stack; usable in `const` and `static` contexts
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::ArrayStack;
+    /// let stack: ArrayStack<u8, 32> = ArrayStack::new();
+    /// assert!(stack.is_empty());
+    /// assert_eq!(stack.capacity(), 32);
+    /// ```
+    pub const fn new() -> Self {
+        Self { buf: [const { MaybeUninit::uninit() }; N], len: 0 }
+    }
+
+    /// Pushes a value if there is room (REQ-F1, REQ-F2)
+    ///
+    /// # Returns
+    /// * `Ok(())` - The value is now on top of the stack
+    /// * `Err(x)` - The stack is full; ownership of `x` is handed back
+    pub fn try_push(&mut self, x: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(x);
+        }
+        self.buf[self.len].write(x);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Pops and returns the top value (O(1), ownership is transferred)
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: slot `len` was initialized and is now logically removed
+        Some(unsafe { self.buf[self.len].assume_init_read() })
+    }
+
+    /// Returns an immutable reference to the top value without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Returns a mutable reference to the top value without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    /// Returns the number of elements in the stack
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the stack contains no elements
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the next `try_push` would fail
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the fixed capacity `N`
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns an iterator over the elements from top to bottom
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::ArrayStack;
+    /// let mut stack: ArrayStack<i32, 4> = ArrayStack::new();
+    /// stack.try_push(1).unwrap();
+    /// stack.try_push(2).unwrap();
+    /// assert_eq!(stack.iter().next_back(), Some(&1)); // Bottom element
+    /// ```
+    pub fn iter(&self) -> core::iter::Rev<core::slice::Iter<'_, T>> {
+        self.as_slice().iter().rev()
+    }
+
+    /// Removes and drops every element
+    pub fn clear(&mut self) {
+        let len = self.len;
+        self.len = 0; // Set first so a panicking Drop cannot cause a double drop
+        for slot in &mut self.buf[..len] {
+            // SAFETY: slots below the old length were initialized
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+
+    /// The live elements in bottom-to-top order
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` slots are initialized, and MaybeUninit<T>
+        // has the same layout as T
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast::<T>(), self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: as in `as_slice`
+        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast::<T>(), self.len) }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayStack<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for ArrayStack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayStack")
+            .field("items", &self.as_slice())
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test] // REQ-F1, REQ-F2
+    fn fills_to_capacity_then_rejects() {
+        let mut s: ArrayStack<u32, 3> = ArrayStack::new();
+        for i in 0..3 {
+            assert!(s.try_push(i).is_ok());
+        }
+        assert!(s.is_full());
+        assert_eq!(s.try_push(7), Err(7));
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.peek(), Some(&2));
+        *s.peek_mut().unwrap() = 20;
+        assert_eq!(s.pop(), Some(20));
+        assert!(s.try_push(8).is_ok());
+        assert!(s.iter().eq([8, 1, 0].iter()));
+    }
+
+    #[test] // REQ-F1: size is fixed at compile time
+    fn no_hidden_heap_storage() {
+        assert_eq!(
+            core::mem::size_of::<ArrayStack<u64, 16>>(),
+            16 * core::mem::size_of::<u64>() + core::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn remaining_elements_are_dropped() {
+        struct Counted<'a>(&'a core::cell::Cell<usize>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = core::cell::Cell::new(0);
+        {
+            let mut s: ArrayStack<Counted, 4> = ArrayStack::new();
+            for _ in 0..4 {
+                assert!(s.try_push(Counted(&drops)).is_ok());
+            }
+            drop(s.pop());
+            assert_eq!(drops.get(), 1);
+        }
+        assert_eq!(drops.get(), 4);
+    }
+}
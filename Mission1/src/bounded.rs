@@ -0,0 +1,260 @@
+// A capacity-limited stack for untrusted input
+//
+// The plain Stack<T> grows without limit, which lets hostile input (e.g. a
+// million opening brackets) consume unbounded memory. BoundedStack caps the
+// number of stored elements and lets the caller choose what happens on overflow.
+//
+// REQ-B1: The stack shall never hold more than `capacity` elements
+// REQ-B2: try_push shall hand the value back as Err(x) when full (backpressure),
+//         mirroring mission2::RingBufferQueue::enqueue
+// REQ-B3: The overflow behaviour shall be selectable (reject, drop-bottom, panic)
+
+use std::collections::VecDeque;
+
+/// What a [`BoundedStack`] does when a push would exceed its capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Refuse the new value and return it to the caller as `Err(x)` (REQ-B2)
+    #[default]
+    Reject,
+    /// Discard the oldest (bottom) element to make room for the new one
+    DropBottom,
+    /// Treat overflow as a bug and panic
+    Panic,
+}
+
+/// A Last-In-First-Out stack with a hard upper bound on its length
+///
+/// # Type Parameters
+/// * `T` - The type of elements stored in the stack
+///
+/// # Memory Safety
+/// Storage is allocated once, up front, for exactly `capacity` elements and
+/// never grows afterwards. A `VecDeque` is used instead of a `Vec` so that the
+/// [`OverflowPolicy::DropBottom`] policy can evict the bottom element in O(1).
+///
+/// # Examples
+/// ```
+/// use mission1::{BoundedStack, OverflowPolicy};
+///
+/// let mut stack = BoundedStack::with_capacity(2);
+/// assert!(stack.try_push(1).is_ok());
+/// assert!(stack.try_push(2).is_ok());
+/// assert_eq!(stack.try_push(3), Err(3)); // Full - value handed back
+///
+/// let mut recent = BoundedStack::with_policy(2, OverflowPolicy::DropBottom);
+/// for i in 1..=4 {
+///     recent.try_push(i).unwrap();
+/// }
+/// assert_eq!(recent.pop(), Some(4));
+/// assert_eq!(recent.pop(), Some(3)); // 1 and 2 were dropped from the bottom
+/// assert_eq!(recent.pop(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedStack<T> {
+    /// Bottom of the stack is the front of the deque, top is the back
+    items: VecDeque<T>,
+    /// Maximum number of elements (REQ-B1)
+    capacity: usize,
+    /// Behaviour when full (REQ-B3)
+    policy: OverflowPolicy,
+}
+
+impl<T> BoundedStack<T> {
+    /// Creates an empty stack that rejects pushes beyond `capacity`
+    ///
+    /// # Panics
+    /// Panics if `capacity` is 0, as this would create an unusable stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::BoundedStack;
+    /// let stack: BoundedStack<i32> = BoundedStack::with_capacity(8);
+    /// assert_eq!(stack.capacity(), 8);
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_policy(capacity, OverflowPolicy::Reject)
+    }
+
+    /// Creates an empty stack with the given capacity and overflow policy
+    ///
+    /// # Panics
+    /// Panics if `capacity` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::{BoundedStack, OverflowPolicy};
+    /// let stack: BoundedStack<u8> = BoundedStack::with_policy(4, OverflowPolicy::Panic);
+    /// assert_eq!(stack.policy(), OverflowPolicy::Panic);
+    /// ```
+    pub fn with_policy(capacity: usize, policy: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "capacity must be > 0");
+        Self {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+            policy,
+        }
+    }
+
+    /// Pushes a value, applying the overflow policy if the stack is full (REQ-B2, REQ-B3)
+    ///
+    /// # Returns
+    /// * `Ok(())` - The value is now on top of the stack
+    /// * `Err(x)` - The stack was full under [`OverflowPolicy::Reject`];
+    ///   ownership of `x` is handed back to the caller
+    ///
+    /// # Panics
+    /// Panics if the stack is full and the policy is [`OverflowPolicy::Panic`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::BoundedStack;
+    /// let mut stack = BoundedStack::with_capacity(1);
+    /// assert!(stack.try_push(String::from("kept")).is_ok());
+    ///
+    /// let rejected = stack.try_push(String::from("rejected")).unwrap_err();
+    /// assert_eq!(rejected, "rejected"); // We still own it
+    /// ```
+    pub fn try_push(&mut self, x: T) -> Result<(), T> {
+        if self.is_full() {
+            match self.policy {
+                OverflowPolicy::Reject => return Err(x),
+                OverflowPolicy::DropBottom => {
+                    self.items.pop_front(); // Evict the oldest element
+                }
+                OverflowPolicy::Panic => {
+                    panic!("BoundedStack overflow: capacity {} exceeded", self.capacity)
+                }
+            }
+        }
+        self.items.push_back(x);
+        Ok(())
+    }
+
+    /// Pops and returns the top value (O(1), ownership is transferred)
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::BoundedStack;
+    /// let mut stack = BoundedStack::with_capacity(2);
+    /// stack.try_push("a").unwrap();
+    /// assert_eq!(stack.pop(), Some("a"));
+    /// assert_eq!(stack.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
+
+    /// Returns an immutable reference to the top value without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.items.back()
+    }
+
+    /// Returns a mutable reference to the top value without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.items.back_mut()
+    }
+
+    /// Returns the number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the stack contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns true if the next push would trigger the overflow policy
+    pub fn is_full(&self) -> bool {
+        self.items.len() == self.capacity
+    }
+
+    /// Returns the maximum number of elements the stack can hold (REQ-B1)
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the active overflow policy
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Changes the overflow policy; existing elements are unaffected
+    pub fn set_policy(&mut self, policy: OverflowPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns an iterator over the elements from top to bottom
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::BoundedStack;
+    /// let mut stack = BoundedStack::with_capacity(3);
+    /// stack.try_push(1).unwrap();
+    /// stack.try_push(2).unwrap();
+    /// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&2, &1]);
+    /// ```
+    pub fn iter(&self) -> std::iter::Rev<std::collections::vec_deque::Iter<'_, T>> {
+        self.items.iter().rev()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test] // REQ-B1, REQ-B2
+    fn reject_policy_returns_value_and_keeps_contents() {
+        let mut s = BoundedStack::with_capacity(3);
+        for i in 0..3 {
+            assert!(s.try_push(i).is_ok());
+        }
+        assert!(s.is_full());
+        assert_eq!(s.try_push(99), Err(99));
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.peek(), Some(&2));
+
+        // Popping frees a slot again
+        assert_eq!(s.pop(), Some(2));
+        assert!(s.try_push(42).is_ok());
+        assert_eq!(s.peek(), Some(&42));
+    }
+
+    #[test] // REQ-B1, REQ-B3
+    fn drop_bottom_policy_keeps_most_recent() {
+        let mut s = BoundedStack::with_policy(3, OverflowPolicy::DropBottom);
+        for i in 0..10 {
+            assert!(s.try_push(i).is_ok());
+            assert!(s.len() <= 3);
+        }
+        let remaining: Vec<i32> = s.iter().copied().collect();
+        assert_eq!(remaining, vec![9, 8, 7]);
+    }
+
+    #[test] // REQ-B3
+    #[should_panic(expected = "BoundedStack overflow")]
+    fn panic_policy_panics_on_overflow() {
+        let mut s = BoundedStack::with_policy(1, OverflowPolicy::Panic);
+        s.try_push(1).unwrap();
+        let _ = s.try_push(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be > 0")]
+    fn zero_capacity_is_rejected() {
+        let _s: BoundedStack<i32> = BoundedStack::with_capacity(0);
+    }
+
+    #[test] // REQ-B1: storage never grows past the requested bound
+    fn hostile_input_is_capped() {
+        let mut s = BoundedStack::with_capacity(16);
+        let rejected = "(".repeat(10_000)
+            .char_indices()
+            .filter(|&(i, ch)| s.try_push((ch, i)).is_err())
+            .count();
+        assert_eq!(s.len(), 16);
+        assert_eq!(rejected, 10_000 - 16);
+    }
+}
@@ -0,0 +1,284 @@
+// Undo journal behind Stack::checkpoint / rollback_to / commit
+//
+// While at least one checkpoint is open, every mutation of a Stack appends an
+// entry describing how to undo it. Rolling back replays those entries in
+// reverse, so the cost is O(changes since the mark) rather than O(len).
+//
+// REQ-7: The stack shall support nested checkpoints that restore its exact
+//        earlier contents (including popped values) in O(changes) time
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Opaque token identifying an open checkpoint on a [`Stack`](crate::Stack)
+///
+/// Returned by [`Stack::checkpoint`](crate::Stack::checkpoint) and consumed by
+/// [`Stack::rollback_to`](crate::Stack::rollback_to) or
+/// [`Stack::commit`](crate::Stack::commit). The token is deliberately neither
+/// `Clone` nor `Copy`: ownership guarantees a checkpoint is resolved at most once.
+#[derive(Debug, PartialEq, Eq)]
+#[must_use = "an open checkpoint keeps journaling until it is committed or rolled back"]
+pub struct Checkpoint {
+    serial: u64,
+}
+
+/// One undoable mutation
+#[derive(Debug)]
+enum Entry<T> {
+    /// `n` consecutive pushes; undone by truncating
+    Pushed(usize),
+    /// A value that was removed from the top; undone by pushing it back
+    Popped(T),
+    /// The previous value of the slot at `index`, captured before a mutable borrow
+    Replaced { index: usize, old: T },
+}
+
+/// Source of checkpoint serial numbers, unique across all stacks so a stale or
+/// foreign token can never be mistaken for an open one
+static NEXT_SERIAL: AtomicU64 = AtomicU64::new(0);
+
+/// Journal of mutations made since the oldest open checkpoint
+///
+/// The journal is inert while no checkpoint is open: it is a single `None`
+/// pointer, so a `Stack` that never checkpoints pays one word and one branch.
+#[derive(Debug)]
+pub(crate) struct Journal<T> {
+    state: Option<Box<JournalState<T>>>,
+}
+
+#[derive(Debug)]
+struct JournalState<T> {
+    entries: Vec<Entry<T>>,
+    /// Open checkpoints, oldest first: (serial, journal length at the mark)
+    marks: Vec<(u64, usize)>,
+    /// `T::clone`, captured when the first checkpoint opens.
+    /// Stored as a function pointer so `Stack<T>` itself needs no `T: Clone` bound.
+    cloner: fn(&T) -> T,
+}
+
+impl<T> Default for Journal<T> {
+    fn default() -> Self {
+        Self { state: None }
+    }
+}
+
+impl<T> Journal<T> {
+    /// Opens a new (innermost) checkpoint
+    pub(crate) fn open(&mut self, cloner: fn(&T) -> T) -> Checkpoint {
+        let serial = NEXT_SERIAL.fetch_add(1, Ordering::Relaxed);
+        let state = self.state.get_or_insert_with(|| {
+            Box::new(JournalState { entries: Vec::new(), marks: Vec::new(), cloner })
+        });
+        state.marks.push((serial, state.entries.len()));
+        Checkpoint { serial }
+    }
+
+    /// True while at least one checkpoint is open and mutations are being journaled
+    #[inline]
+    pub(crate) fn is_recording(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// Number of currently open checkpoints
+    pub(crate) fn depth(&self) -> usize {
+        self.state.as_ref().map_or(0, |state| state.marks.len())
+    }
+
+    /// Records `n` pushes onto the top of the stack
+    #[inline]
+    pub(crate) fn record_push(&mut self, n: usize) {
+        let Some(state) = self.state.as_mut() else { return };
+        if n == 0 {
+            return;
+        }
+        // Only merge with an entry made after the innermost mark: an entry from
+        // before it belongs to an outer checkpoint and must not grow
+        let mark = state.marks.last().map_or(0, |&(_, at)| at);
+        let mergeable = state.entries.len() > mark;
+        match state.entries.last_mut() {
+            Some(Entry::Pushed(count)) if mergeable => *count += n,
+            _ => state.entries.push(Entry::Pushed(n)),
+        }
+    }
+
+    /// Records that `value` was removed from the top of the stack
+    #[inline]
+    pub(crate) fn record_pop(&mut self, value: &T) {
+        if let Some(state) = self.state.as_mut() {
+            state.entries.push(Entry::Popped((state.cloner)(value)));
+        }
+    }
+
+    /// Records `current`, the contents of slot `index`, before it is mutated in place
+    #[inline]
+    pub(crate) fn record_replace(&mut self, index: usize, current: &T) {
+        if let Some(state) = self.state.as_mut() {
+            state.entries.push(Entry::Replaced { index, old: (state.cloner)(current) });
+        }
+    }
+
+    /// The journal state and the position of `cp` in its marks, panicking if `cp` is not open
+    fn find(&mut self, cp: &Checkpoint) -> (&mut JournalState<T>, usize) {
+        let found = self.state.as_deref_mut().and_then(|state| {
+            let at = state.marks.iter().rposition(|&(serial, _)| serial == cp.serial)?;
+            Some((state, at))
+        });
+        found.expect("checkpoint is not open on this stack (already resolved or discarded)")
+    }
+
+    /// Undoes every mutation since `cp` and closes it along with any inner checkpoints
+    pub(crate) fn rollback(&mut self, items: &mut Vec<T>, cp: Checkpoint) {
+        let (state, at) = self.find(&cp);
+        let (_, mark) = state.marks[at];
+        while state.entries.len() > mark {
+            match state.entries.pop() {
+                Some(Entry::Pushed(n)) => items.truncate(items.len() - n),
+                Some(Entry::Popped(value)) => items.push(value),
+                Some(Entry::Replaced { index, old }) => items[index] = old,
+                None => unreachable!(),
+            }
+        }
+        self.close_from(at);
+    }
+
+    /// Keeps every mutation since `cp` and closes it along with any inner checkpoints
+    pub(crate) fn commit(&mut self, cp: Checkpoint) {
+        let (_, at) = self.find(&cp);
+        self.close_from(at);
+    }
+
+    fn close_from(&mut self, at: usize) {
+        if let Some(state) = self.state.as_mut() {
+            state.marks.truncate(at);
+            if state.marks.is_empty() {
+                // Nobody can roll back any more: release the saved values
+                self.state = None;
+            }
+        }
+    }
+
+    /// Number of undo entries currently held
+    #[cfg(test)]
+    fn entry_count(&self) -> usize {
+        self.state.as_ref().map_or(0, |state| state.entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Stack;
+
+    #[test] // REQ-7
+    fn rollback_restores_pushes_pops_and_edits() {
+        let mut s: Stack<String> = ["a", "b", "c"].iter().map(|x| x.to_string()).collect();
+        let cp = s.checkpoint();
+
+        assert_eq!(s.pop().as_deref(), Some("c"));
+        assert_eq!(s.pop().as_deref(), Some("b"));
+        s.push("x".to_string());
+        *s.peek_mut().unwrap() = "edited".to_string();
+        s.push("y".to_string());
+
+        s.rollback_to(cp);
+        let restored: Vec<&str> = s.iter().map(|x| x.as_str()).collect();
+        assert_eq!(restored, vec!["c", "b", "a"]);
+    }
+
+    #[test] // REQ-7
+    fn nested_checkpoints_roll_back_independently() {
+        let mut s: Stack<i32> = (0..3).collect();
+        let outer = s.checkpoint();
+        s.push(10);
+        let inner = s.checkpoint();
+        s.pop();
+        s.pop();
+        s.rollback_to(inner);
+        assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![10, 2, 1, 0]);
+
+        let inner = s.checkpoint();
+        s.push(20);
+        s.commit(inner); // Keeps 20, but the outer checkpoint can still undo it
+        assert_eq!(s.peek(), Some(&20));
+
+        s.rollback_to(outer);
+        assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![2, 1, 0]);
+        assert_eq!(s.open_checkpoints(), 0);
+    }
+
+    #[test] // REQ-7
+    fn pushes_either_side_of_a_mark_roll_back_separately() {
+        let mut s = Stack::new();
+        let outer = s.checkpoint();
+        s.push(1);
+        let inner = s.checkpoint();
+        s.push(2);
+        s.push(3);
+        s.rollback_to(inner);
+        assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![1]);
+
+        s.push(4);
+        s.rollback_to(outer);
+        assert!(s.is_empty());
+    }
+
+    #[test] // REQ-7
+    fn commit_keeps_changes_and_stops_journaling() {
+        let mut s = Stack::new();
+        s.push(1);
+        let cp = s.checkpoint();
+        s.push(2);
+        s.commit(cp);
+        assert_eq!(s.open_checkpoints(), 0);
+        assert_eq!(s.journal.entry_count(), 0);
+
+        s.pop();
+        s.push(3);
+        assert_eq!(s.journal.entry_count(), 0); // Nothing recorded without a checkpoint
+        assert_eq!(s.peek(), Some(&3));
+    }
+
+    #[test] // REQ-7
+    fn journal_cost_tracks_changes_not_length() {
+        let mut s: Stack<u32> = (0..100_000).collect();
+        let cp = s.checkpoint();
+        for i in 0..5 {
+            s.push(i);
+        }
+        s.pop();
+        s.pop();
+        // Consecutive pushes share an entry; each pop keeps exactly one value
+        assert_eq!(s.journal.entry_count(), 3);
+        s.rollback_to(cp);
+        assert_eq!(s.len(), 100_000);
+        assert_eq!(s.peek(), Some(&99_999));
+    }
+
+    #[test] // REQ-7
+    fn iter_mut_journals_only_visited_elements() {
+        let mut s: Stack<u32> = (0..100_000).collect();
+        s.iter_mut().take(3).for_each(|x| *x += 1);
+        assert_eq!(s.journal.entry_count(), 0); // Nothing recorded without a checkpoint
+
+        let cp = s.checkpoint();
+        for x in s.iter_mut().take(2) {
+            *x = 0;
+        }
+        *s.iter_mut().next_back().unwrap() = 7;
+        assert_eq!(s.journal.entry_count(), 3);
+        s.rollback_to(cp);
+        assert_eq!(s.iter().take(3).copied().collect::<Vec<_>>(), vec![100_000, 99_999, 99_998]);
+        assert_eq!(s.iter().next_back(), Some(&0));
+    }
+
+    #[test] // REQ-7
+    fn rolling_back_outer_discards_inner() {
+        let mut s = Stack::new();
+        let outer = s.checkpoint();
+        s.push('a');
+        let inner = s.checkpoint();
+        s.push('b');
+        s.rollback_to(outer);
+        assert!(s.is_empty());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| s.commit(inner)));
+        assert!(result.is_err(), "inner checkpoint must be invalid after outer rollback");
+    }
+}
@@ -0,0 +1,413 @@
+// A lock-free concurrent stack (Treiber stack) with hazard-pointer reclamation
+//
+// Stack<T> needs `&mut self` for push/pop, so sharing it between threads means
+// wrapping it in a Mutex. ConcurrentStack<T> instead works through `&self`:
+// the top of the stack is a single AtomicPtr that is updated with compare-and-swap.
+//
+// The hard part of any lock-free linked structure is deciding when a popped node
+// may be freed - another thread might have loaded the same pointer a moment ago
+// and be about to read its `next` field. We solve this with hazard pointers:
+// before dereferencing a node, a thread publishes its address in a hazard slot;
+// popped nodes are "retired" and only freed once no slot mentions them. Because
+// a protected node can never be freed, its address cannot be reused either,
+// which also rules out the classic ABA problem.
+//
+// REQ-C1: push and pop shall take `&self` and be safe to call from many threads
+// REQ-C2: no value shall be lost or returned twice under contention
+// REQ-C3: popped nodes shall be reclaimed without use-after-free (hazard pointers)
+// REQ-C4: dropping the stack shall drop every remaining value exactly once
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+/// Retired nodes are scanned for reclamation once this many have accumulated
+const RECLAIM_THRESHOLD: usize = 64;
+
+/// A heap node. `next` is written once before the node is published and never changes.
+struct Node<T> {
+    /// Moved out by the thread whose pop succeeds, so the node itself must not drop it
+    value: ManuallyDrop<T>,
+    next: *mut Node<T>,
+    /// Link in the retired list; only touched by the retiring/reclaiming thread
+    retired_next: *mut Node<T>,
+}
+
+/// One published "I am about to dereference this pointer" announcement
+struct HazardSlot {
+    ptr: AtomicPtr<()>,
+    active: AtomicBool,
+    /// Slots are only ever prepended and live until the stack is dropped
+    next: *mut HazardSlot,
+}
+
+/// RAII ownership of a hazard slot for the duration of one operation
+struct HazardGuard<'a> {
+    slot: &'a HazardSlot,
+}
+
+impl HazardGuard<'_> {
+    fn protect(&self, p: *mut ()) {
+        self.slot.ptr.store(p, Ordering::SeqCst);
+    }
+
+    fn clear(&self) {
+        self.slot.ptr.store(ptr::null_mut(), Ordering::Release);
+    }
+}
+
+impl Drop for HazardGuard<'_> {
+    fn drop(&mut self) {
+        self.clear();
+        self.slot.active.store(false, Ordering::Release);
+    }
+}
+
+/// A lock-free LIFO stack that can be shared between threads
+///
+/// # Type Parameters
+/// * `T` - The type of elements stored in the stack
+///
+/// # Ownership
+/// Values are moved in by [`push`](ConcurrentStack::push) and moved out by
+/// [`pop`](ConcurrentStack::pop). There is no `peek`: a reference into the
+/// stack could be invalidated by another thread's pop at any moment.
+///
+/// # Progress
+/// Push and pop never block. If a compare-and-swap fails it is because another
+/// thread's operation succeeded, so the system as a whole always makes progress.
+///
+/// # Examples
+/// ```
+/// use mission1::ConcurrentStack;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let stack = Arc::new(ConcurrentStack::new());
+/// let handles: Vec<_> = (0..4)
+///     .map(|t| {
+///         let stack = Arc::clone(&stack);
+///         thread::spawn(move || {
+///             for i in 0..100 {
+///                 stack.push(t * 100 + i);
+///             }
+///         })
+///     })
+///     .collect();
+/// for h in handles {
+///     h.join().unwrap();
+/// }
+///
+/// let mut count = 0;
+/// while stack.pop().is_some() {
+///     count += 1;
+/// }
+/// assert_eq!(count, 400);
+/// ```
+pub struct ConcurrentStack<T> {
+    head: AtomicPtr<Node<T>>,
+    len: AtomicUsize,
+    /// Registry of hazard slots, grown on demand (one per concurrently active pop)
+    hazards: AtomicPtr<HazardSlot>,
+    /// Nodes that have been popped but may still be protected by a hazard
+    retired: AtomicPtr<Node<T>>,
+    retired_count: AtomicUsize,
+    /// The stack logically owns boxed `Node<T>`s
+    _owns: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: values are only ever moved between threads, never shared by reference,
+// so `T: Send` is sufficient for both sending and sharing the stack.
+unsafe impl<T: Send> Send for ConcurrentStack<T> {}
+unsafe impl<T: Send> Sync for ConcurrentStack<T> {}
+
+impl<T> ConcurrentStack<T> {
+    /// Creates a new empty stack
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::ConcurrentStack;
+    /// let stack: ConcurrentStack<i32> = ConcurrentStack::new();
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+            len: AtomicUsize::new(0),
+            hazards: AtomicPtr::new(ptr::null_mut()),
+            retired: AtomicPtr::new(ptr::null_mut()),
+            retired_count: AtomicUsize::new(0),
+            _owns: PhantomData,
+        }
+    }
+
+    /// Pushes a value onto the top of the stack (REQ-C1, lock-free)
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::ConcurrentStack;
+    /// let stack = ConcurrentStack::new();
+    /// stack.push("shared");          // Only needs &self
+    /// assert_eq!(stack.pop(), Some("shared"));
+    /// ```
+    pub fn push(&self, x: T) {
+        let node = Box::into_raw(Box::new(Node {
+            value: ManuallyDrop::new(x),
+            next: ptr::null_mut(),
+            retired_next: ptr::null_mut(),
+        }));
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // SAFETY: `node` is not yet published, so we have exclusive access
+            unsafe { (*node).next = head };
+            // Release publishes the node's contents to whoever loads it next
+            match self.head.compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+        self.len.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Pops and returns the top value, or `None` if the stack is empty (REQ-C1, REQ-C2)
+    ///
+    /// Exactly one thread wins each node: the one whose compare-and-swap moves
+    /// `head` past it. That thread moves the value out and retires the node.
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::ConcurrentStack;
+    /// let stack = ConcurrentStack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// assert_eq!(stack.pop(), Some(2));
+    /// assert_eq!(stack.pop(), Some(1));
+    /// assert_eq!(stack.pop(), None);
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        let guard = self.acquire_hazard();
+        let node = loop {
+            let head = self.head.load(Ordering::Acquire);
+            if head.is_null() {
+                return None;
+            }
+            // Announce the pointer, then check it is still the head. If it is,
+            // any thread that retires it later is guaranteed to see our hazard.
+            guard.protect(head.cast());
+            if self.head.load(Ordering::SeqCst) != head {
+                continue;
+            }
+            // SAFETY: `head` is protected, so it cannot have been freed; `next`
+            // is immutable after publication.
+            let next = unsafe { (*head).next };
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::SeqCst, Ordering::Acquire)
+                .is_ok()
+            {
+                break head;
+            }
+        };
+        guard.clear();
+        drop(guard);
+        self.len.fetch_sub(1, Ordering::Relaxed);
+
+        // SAFETY: our CAS unlinked `node`, so we are the only thread that will
+        // ever take its value. The node memory itself is freed by `retire`.
+        let value = unsafe { ManuallyDrop::take(&mut (*node).value) };
+        self.retire(node);
+        Some(value)
+    }
+
+    /// Returns the number of elements at the moment of the call
+    ///
+    /// Under concurrent use this is only a snapshot; it may be stale by the
+    /// time the caller looks at it.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the stack was empty at the moment of the call
+    pub fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Acquire).is_null()
+    }
+
+    /// Claims an inactive hazard slot, registering a new one if all are busy
+    fn acquire_hazard(&self) -> HazardGuard<'_> {
+        let mut cur = self.hazards.load(Ordering::Acquire);
+        while !cur.is_null() {
+            // SAFETY: slots are never freed while the stack is alive
+            let slot = unsafe { &*cur };
+            if !slot.active.load(Ordering::Relaxed)
+                && slot
+                    .active
+                    .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                return HazardGuard { slot };
+            }
+            cur = slot.next;
+        }
+
+        let slot = Box::into_raw(Box::new(HazardSlot {
+            ptr: AtomicPtr::new(ptr::null_mut()),
+            active: AtomicBool::new(true),
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.hazards.load(Ordering::Relaxed);
+        loop {
+            // SAFETY: `slot` is not yet published
+            unsafe { (*slot).next = head };
+            match self.hazards.compare_exchange_weak(head, slot, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+        // SAFETY: just published; lives until the stack is dropped
+        HazardGuard { slot: unsafe { &*slot } }
+    }
+
+    /// Adds an unlinked node to the retired list and reclaims if enough have piled up (REQ-C3)
+    fn retire(&self, node: *mut Node<T>) {
+        self.push_retired(node);
+        if self.retired_count.fetch_add(1, Ordering::Relaxed) + 1 >= RECLAIM_THRESHOLD {
+            self.reclaim();
+        }
+    }
+
+    fn push_retired(&self, node: *mut Node<T>) {
+        let mut head = self.retired.load(Ordering::Relaxed);
+        loop {
+            // SAFETY: only the retiring thread touches `retired_next` until the
+            // node is published on the retired list
+            unsafe { (*node).retired_next = head };
+            match self.retired.compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Frees every retired node that no hazard slot currently protects
+    fn reclaim(&self) {
+        // Taking the whole list with a swap gives us exclusive ownership of it
+        let mut node = self.retired.swap(ptr::null_mut(), Ordering::Acquire);
+        if node.is_null() {
+            return;
+        }
+        self.retired_count.store(0, Ordering::Relaxed);
+
+        let mut protected = Vec::new();
+        let mut slot = self.hazards.load(Ordering::Acquire);
+        while !slot.is_null() {
+            // SAFETY: slots are never freed while the stack is alive
+            let s = unsafe { &*slot };
+            let p = s.ptr.load(Ordering::SeqCst);
+            if !p.is_null() {
+                protected.push(p);
+            }
+            slot = s.next;
+        }
+
+        let mut kept = 0;
+        while !node.is_null() {
+            // SAFETY: we own the detached retired list
+            let next = unsafe { (*node).retired_next };
+            if protected.contains(&node.cast()) {
+                self.push_retired(node);
+                kept += 1;
+            } else {
+                // SAFETY: unlinked, value already moved out, and no hazard refers to it
+                drop(unsafe { Box::from_raw(node) });
+            }
+            node = next;
+        }
+        self.retired_count.fetch_add(kept, Ordering::Relaxed);
+    }
+}
+
+impl<T> Default for ConcurrentStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ConcurrentStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrentStack").field("len", &self.len()).finish_non_exhaustive()
+    }
+}
+
+impl<T> Drop for ConcurrentStack<T> {
+    /// With `&mut self` no other thread can be inside push/pop, so everything
+    /// can be freed directly (REQ-C4)
+    fn drop(&mut self) {
+        let mut node = *self.head.get_mut();
+        while !node.is_null() {
+            // SAFETY: exclusive access; these values were never popped
+            let mut boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next;
+            unsafe { ManuallyDrop::drop(&mut boxed.value) };
+        }
+        let mut node = *self.retired.get_mut();
+        while !node.is_null() {
+            // SAFETY: exclusive access; retired values were already moved out
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.retired_next;
+        }
+        let mut slot = *self.hazards.get_mut();
+        while !slot.is_null() {
+            // SAFETY: no guard can outlive `&self`, so every slot is idle
+            let boxed = unsafe { Box::from_raw(slot) };
+            slot = boxed.next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test] // REQ-C1
+    fn single_thread_lifo_order() {
+        let s = ConcurrentStack::new();
+        for i in 0..10 {
+            s.push(i);
+        }
+        assert_eq!(s.len(), 10);
+        for i in (0..10).rev() {
+            assert_eq!(s.pop(), Some(i));
+        }
+        assert_eq!(s.pop(), None);
+        assert!(s.is_empty());
+    }
+
+    #[test] // REQ-C3: retired nodes are eventually freed, not leaked
+    fn retired_list_is_bounded() {
+        let s = ConcurrentStack::new();
+        for i in 0..10 * RECLAIM_THRESHOLD {
+            s.push(i);
+            s.pop();
+        }
+        assert!(s.retired_count.load(Ordering::Relaxed) < RECLAIM_THRESHOLD);
+    }
+
+    #[test] // REQ-C4
+    fn drop_releases_remaining_values_once() {
+        let marker = Arc::new(());
+        {
+            let s = ConcurrentStack::new();
+            for _ in 0..100 {
+                s.push(Arc::clone(&marker));
+            }
+            for _ in 0..40 {
+                drop(s.pop());
+            }
+            assert_eq!(Arc::strong_count(&marker), 61);
+        }
+        assert_eq!(Arc::strong_count(&marker), 1);
+    }
+}
@@ -0,0 +1,387 @@
+// Model-based conformance checks for LIFO implementations
+//
+// tests/stack_tests.rs checks Stack against hand-picked sequences. This
+// module generates random operation sequences instead, runs each one against
+// a Vec reference model and against the stack under test (anything that
+// implements Lifo), and on a mismatch shrinks the sequence to a minimal
+// reproduction. Values are Probes that count how many copies are alive, so
+// leaks and double drops are caught as well as wrong results.
+//
+// REQ-K1: Operation sequences shall be generated from a seed and be reproducible
+// REQ-K2: Every step shall be compared with a Vec model: popped/peeked values,
+//         len, is_empty and (for bounded stacks) rejected pushes (REQ-1..REQ-5)
+// REQ-K3: Every value shall be dropped exactly once by the end of a case (REQ-3, REQ-4)
+// REQ-K4: Failing sequences shall be shrunk to a minimal reproduction
+
+use crate::Lifo;
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+/// One step of a generated test case
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// `try_push` a probe carrying this id
+    Push(u64),
+    /// `pop` and compare the id
+    Pop,
+    /// `peek` and compare the id
+    Peek,
+}
+
+/// A tracked value: counts its live copies so leaks and double drops show up (REQ-K3)
+#[derive(Debug)]
+pub struct Probe {
+    id: u64,
+    live: Rc<Cell<i64>>,
+}
+
+impl Probe {
+    fn new(id: u64, live: &Rc<Cell<i64>>) -> Self {
+        live.set(live.get() + 1);
+        Self { id, live: Rc::clone(live) }
+    }
+
+    /// The id given by the [`Operation::Push`] that created this probe
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+/// Cloning is tracked too, for stacks that keep copies (e.g. `AggregateStack`)
+impl Clone for Probe {
+    fn clone(&self) -> Self {
+        Self::new(self.id, &self.live)
+    }
+}
+
+impl Drop for Probe {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+/// Settings for [`check`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Number of random sequences to run
+    pub cases: usize,
+    /// Maximum number of operations per sequence
+    pub max_ops: usize,
+    /// Seed for the first case; case `i` uses `seed + i` (REQ-K1)
+    pub seed: u64,
+    /// `Some(n)` for stacks that must refuse pushes beyond `n` elements
+    pub capacity: Option<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { cases: 200, max_ops: 100, seed: 0, capacity: None }
+    }
+}
+
+/// A failing case after shrinking (REQ-K4)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// Seed of the case that failed; rerun with `Config { seed, cases: 1, .. }`
+    pub seed: u64,
+    /// Minimal operation sequence that still fails
+    pub ops: Vec<Operation>,
+    /// Index in `ops` of the failing step (`ops.len()` for a drop-count failure)
+    pub step: usize,
+    /// What differed from the model
+    pub message: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "seed {}: step {}: {}", self.seed, self.step, self.message)?;
+        write!(f, "minimal sequence: {:?}", self.ops)
+    }
+}
+
+/// SplitMix64: small, fast and good enough for choosing operations
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Generates the operation sequence for `seed` (REQ-K1)
+///
+/// About half the operations are pushes, so sequences reach a useful depth;
+/// push ids are unique within a sequence.
+pub fn generate(seed: u64, max_ops: usize) -> Vec<Operation> {
+    let mut rng = SplitMix64(seed);
+    let len = (rng.next() % (max_ops as u64 + 1)) as usize;
+    let mut next_id = 0;
+    (0..len)
+        .map(|_| match rng.next() % 10 {
+            0..=4 => {
+                next_id += 1;
+                Operation::Push(next_id - 1)
+            }
+            5..=8 => Operation::Pop,
+            _ => Operation::Peek,
+        })
+        .collect()
+}
+
+/// Runs `ops` on a fresh stack from `make`, comparing every step with the model (REQ-K2, REQ-K3)
+///
+/// # Returns
+/// `Err((step, message))` describing the first difference
+pub fn run_ops<S, F>(ops: &[Operation], capacity: Option<usize>, make: &mut F) -> Result<(), (usize, String)>
+where
+    S: Lifo<Probe>,
+    F: FnMut() -> S,
+{
+    let live = Rc::new(Cell::new(0));
+    let mut stack = make();
+    let mut model: Vec<u64> = Vec::new();
+
+    for (step, op) in ops.iter().enumerate() {
+        let fail = |message: String| Err((step, message));
+        match *op {
+            Operation::Push(id) => {
+                let full = capacity.is_some_and(|cap| model.len() == cap);
+                match (stack.try_push(Probe::new(id, &live)), full) {
+                    (Ok(()), false) => model.push(id),
+                    (Err(back), true) if back.id == id => {}
+                    (Err(back), true) => {
+                        return fail(format!("rejected push handed back id {} instead of {}", back.id, id));
+                    }
+                    (Ok(()), true) => return fail(format!("push accepted beyond capacity {}", model.len())),
+                    (Err(_), false) => return fail(format!("push rejected at length {}", model.len())),
+                }
+            }
+            Operation::Pop => {
+                let got = stack.pop().map(|p| p.id);
+                let expected = model.pop();
+                if got != expected {
+                    return fail(format!("pop returned {:?}, expected {:?}", got, expected));
+                }
+            }
+            Operation::Peek => {
+                let got = stack.peek().map(|p| p.id);
+                let expected = model.last().copied();
+                if got != expected {
+                    return fail(format!("peek returned {:?}, expected {:?}", got, expected));
+                }
+            }
+        }
+        if stack.len() != model.len() || stack.is_empty() != model.is_empty() {
+            return fail(format!(
+                "len {} / is_empty {}, expected {} / {}",
+                stack.len(),
+                stack.is_empty(),
+                model.len(),
+                model.is_empty()
+            ));
+        }
+    }
+
+    drop(stack);
+    match live.get() {
+        0 => Ok(()),
+        n if n > 0 => Err((ops.len(), format!("{} value(s) never dropped", n))),
+        n => Err((ops.len(), format!("{} value(s) dropped twice", -n))),
+    }
+}
+
+/// Removes operations from a failing sequence for as long as it keeps failing (REQ-K4)
+///
+/// Tries dropping chunks of halving size, down to single operations, and
+/// repeats a size while it makes progress; the result is 1-minimal: removing
+/// any single operation makes the failure disappear.
+pub fn shrink<S, F>(mut ops: Vec<Operation>, capacity: Option<usize>, make: &mut F) -> Vec<Operation>
+where
+    S: Lifo<Probe>,
+    F: FnMut() -> S,
+{
+    let mut chunk = ops.len().max(1);
+    while chunk > 0 {
+        let mut start = 0;
+        let mut shrunk = false;
+        while start < ops.len() {
+            let end = (start + chunk).min(ops.len());
+            let candidate: Vec<Operation> = ops[..start].iter().chain(&ops[end..]).copied().collect();
+            if run_ops(&candidate, capacity, make).is_err() {
+                ops = candidate;
+                shrunk = true;
+            } else {
+                start += chunk;
+            }
+        }
+        if !shrunk {
+            chunk /= 2;
+        }
+    }
+
+    // Renumber the surviving pushes 0, 1, 2, ... for a readable reproduction
+    let mut next_id = 0;
+    let renumbered: Vec<Operation> = ops
+        .iter()
+        .map(|op| match op {
+            Operation::Push(_) => {
+                next_id += 1;
+                Operation::Push(next_id - 1)
+            }
+            other => *other,
+        })
+        .collect();
+    if run_ops(&renumbered, capacity, make).is_err() { renumbered } else { ops }
+}
+
+/// Checks a stack implementation against the model on `config.cases` random sequences
+///
+/// `make` builds a fresh, empty stack for every run. Panics inside the stack
+/// are not caught; rerun the printed seed to debug them.
+///
+/// # Returns
+/// * `Ok(())` - Every case matched the model
+/// * `Err(Failure)` - The first failing case, shrunk
+///
+/// # Examples
+/// ```
+/// use mission1::conformance::{check, Config};
+/// use mission1::{BoundedStack, Stack};
+///
+/// check(&Config::default(), Stack::new).unwrap();
+///
+/// let bounded = Config { capacity: Some(4), ..Config::default() };
+/// check(&bounded, || BoundedStack::with_capacity(4)).unwrap();
+///
+/// // A capacity the model does not expect is reported, with a tiny reproduction
+/// let failure = check(&Config::default(), || BoundedStack::with_capacity(1)).unwrap_err();
+/// assert_eq!(failure.ops.len(), 2); // Two pushes
+/// ```
+pub fn check<S, F>(config: &Config, mut make: F) -> Result<(), Failure>
+where
+    S: Lifo<Probe>,
+    F: FnMut() -> S,
+{
+    for case in 0..config.cases as u64 {
+        let seed = config.seed.wrapping_add(case);
+        let ops = generate(seed, config.max_ops);
+        if run_ops(&ops, config.capacity, &mut make).is_err() {
+            let ops = shrink(ops, config.capacity, &mut make);
+            let (step, message) = run_ops(&ops, config.capacity, &mut make).expect_err("shrinking keeps the failure");
+            return Err(Failure { seed, ops, step, message });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Stack;
+
+    /// A stack that forgets the element under the top once it holds three
+    struct Leaky(Vec<Probe>);
+
+    impl Lifo<Probe> for Leaky {
+        fn try_push(&mut self, x: Probe) -> Result<(), Probe> {
+            self.0.push(x);
+            if self.0.len() == 3 {
+                std::mem::forget(self.0.remove(1));
+            }
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<Probe> {
+            self.0.pop()
+        }
+
+        fn peek(&self) -> Option<&Probe> {
+            self.0.last()
+        }
+
+        fn bottom(&self) -> Option<&Probe> {
+            self.0.first()
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[test] // REQ-K1
+    fn sequences_are_reproducible() {
+        assert_eq!(generate(7, 50), generate(7, 50));
+        assert_ne!(generate(7, 50), generate(8, 50));
+        let ops = generate(3, 1_000);
+        let pushes: Vec<u64> = ops
+            .iter()
+            .filter_map(|op| match op {
+                Operation::Push(id) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(pushes, (0..pushes.len() as u64).collect::<Vec<_>>());
+    }
+
+    #[test] // REQ-K2
+    fn detects_wrong_values() {
+        let ops = [Operation::Push(0), Operation::Push(1), Operation::Push(2), Operation::Pop];
+        assert!(run_ops(&ops, None, &mut Stack::new).is_ok());
+        // The third push "succeeds", but len is 2 instead of 3
+        let (step, message) = run_ops(&ops, None, &mut || Leaky(Vec::new())).unwrap_err();
+        assert_eq!((step, message.as_str()), (2, "len 2 / is_empty false, expected 3 / false"));
+        // A bounded model expects the third push to be refused
+        assert_eq!(run_ops(&ops, Some(2), &mut Stack::new).unwrap_err().0, 2);
+    }
+
+    #[test] // REQ-K3
+    fn detects_values_that_are_never_dropped() {
+        /// Hands out a clone on pop and leaks the original
+        struct Hoarder(Stack<Probe>);
+
+        impl Lifo<Probe> for Hoarder {
+            fn try_push(&mut self, x: Probe) -> Result<(), Probe> {
+                self.0.push(x);
+                Ok(())
+            }
+
+            fn pop(&mut self) -> Option<Probe> {
+                let x = self.0.pop()?;
+                let copy = x.clone();
+                std::mem::forget(x);
+                Some(copy)
+            }
+
+            fn peek(&self) -> Option<&Probe> {
+                self.0.peek()
+            }
+
+            fn bottom(&self) -> Option<&Probe> {
+                self.0.iter().next_back()
+            }
+
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        let ops = [Operation::Push(0), Operation::Push(1), Operation::Pop];
+        assert_eq!(
+            run_ops(&ops, None, &mut || Hoarder(Stack::new())),
+            Err((3, "1 value(s) never dropped".to_string()))
+        );
+    }
+
+    #[test] // REQ-K4
+    fn shrinks_to_a_minimal_sequence() {
+        let failure = check(&Config::default(), || Leaky(Vec::new())).unwrap_err();
+        assert_eq!(failure.ops, vec![Operation::Push(0), Operation::Push(1), Operation::Push(2)]);
+        assert_eq!(failure.step, 2);
+        assert!(failure.to_string().contains("minimal sequence"));
+    }
+}
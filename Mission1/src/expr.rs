@@ -0,0 +1,629 @@
+// Arithmetic expressions: tokenizer, shunting-yard and RPN evaluation
+//
+// Infix text is turned into positioned tokens, reordered into postfix (RPN)
+// with Dijkstra's shunting-yard algorithm on a Stack of pending operators,
+// and finally evaluated on a Stack<f64> of operands. Every stage reports
+// errors with the byte index where they occurred, like BracketError does.
+//
+// REQ-E1: Tokens shall carry the byte index where they start in the input
+// REQ-E2: Infix shall be converted to RPN honouring precedence and associativity
+//         (+ - < * / % < unary minus < ^, with ^ right-associative)
+// REQ-E3: Unary minus, variables and built-in functions (fixed arity) shall be supported
+// REQ-E4: RPN shall be evaluated with mission1::Stack as the operand stack
+// REQ-E5: Errors shall report the byte index and kind of the first problem found
+
+use crate::Stack;
+use std::collections::HashMap;
+use std::fmt;
+
+/// An arithmetic operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// Binary `+`
+    Add,
+    /// Binary `-`
+    Sub,
+    /// Binary `*`
+    Mul,
+    /// Binary `/`
+    Div,
+    /// Binary `%` (remainder)
+    Rem,
+    /// Binary `^` (power, right-associative)
+    Pow,
+    /// Unary `-` (negation)
+    Neg,
+}
+
+impl Op {
+    /// Binding strength; higher binds tighter (REQ-E2)
+    pub fn precedence(self) -> u8 {
+        match self {
+            Op::Add | Op::Sub => 1,
+            Op::Mul | Op::Div | Op::Rem => 2,
+            Op::Neg => 3,
+            Op::Pow => 4,
+        }
+    }
+
+    /// True for operators that group right-to-left (`2^3^2 == 2^(3^2)`)
+    pub fn is_right_assoc(self) -> bool {
+        matches!(self, Op::Pow | Op::Neg)
+    }
+
+    /// Number of operands the operator consumes
+    pub fn arity(self) -> usize {
+        if self == Op::Neg { 1 } else { 2 }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+            Op::Rem => "%",
+            Op::Pow => "^",
+            Op::Neg => "neg",
+        }
+    }
+}
+
+/// A lexical token of an infix expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    /// A numeric literal such as `3` or `2.5`
+    Number(f64),
+    /// A variable or function name
+    Ident(String),
+    /// An operator; `-` is always tokenized as [`Op::Sub`] and only the parser
+    /// decides whether it is unary
+    Op(Op),
+    /// `(`
+    LParen,
+    /// `)`
+    RParen,
+    /// `,` separating function arguments
+    Comma,
+}
+
+/// One instruction of a postfix (RPN) program
+#[derive(Debug, Clone, PartialEq)]
+pub enum RpnItem {
+    /// Push a constant
+    Number(f64),
+    /// Push the value of a variable
+    Var(String),
+    /// Pop the operator's operands and push the result
+    Op(Op),
+    /// Pop `argc` arguments, call the built-in function and push the result
+    Call { name: String, argc: usize },
+}
+
+impl fmt::Display for RpnItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpnItem::Number(x) => write!(f, "{}", x),
+            RpnItem::Var(name) => f.write_str(name),
+            RpnItem::Op(op) => f.write_str(op.symbol()),
+            RpnItem::Call { name, .. } => f.write_str(name),
+        }
+    }
+}
+
+/// The different ways an expression can fail to tokenize, parse or evaluate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprErrorKind {
+    /// A character that cannot start any token
+    UnexpectedChar { found: char },
+    /// A numeric literal that does not parse, e.g. `1.2.3`
+    InvalidNumber,
+    /// An operand or `(` appeared where an operator was expected, e.g. `2 3`
+    ExpectedOperator,
+    /// An operator, `)` or the end of input appeared where an operand was expected
+    MissingOperand,
+    /// A `)` with no matching `(`
+    UnexpectedClosing,
+    /// A `(` that is never closed; `index` points at it
+    UnclosedParen { open_index: usize },
+    /// A `,` outside the argument list of a function call
+    MisplacedComma,
+    /// A variable with no value in the [`Env`]
+    UnknownVariable { name: String },
+    /// A call to a function that is not built in
+    UnknownFunction { name: String },
+    /// A built-in function called with the wrong number of arguments
+    WrongArity { name: String, expected: usize, found: usize },
+    /// `/` or `%` with a zero right-hand side
+    DivisionByZero,
+    /// An RPN program that leaves more than one value on the stack
+    LeftoverOperands { count: usize },
+}
+
+/// An expression error with the byte index where it was detected (REQ-E5)
+///
+/// # Examples
+/// ```
+/// use mission1::expr::{eval, Env, ExprErrorKind};
+///
+/// let err = eval("2 * (3 + x)", &Env::new()).unwrap_err();
+/// assert_eq!(err.index, 9);
+/// assert_eq!(err.kind, ExprErrorKind::UnknownVariable { name: "x".to_string() });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    /// Byte index in the source text
+    pub index: usize,
+    /// What went wrong
+    pub kind: ExprErrorKind,
+}
+
+impl ExprError {
+    fn new(index: usize, kind: ExprErrorKind) -> Self {
+        Self { index, kind }
+    }
+}
+
+/// Variable bindings used during evaluation
+///
+/// `pi` and `e` are predefined and may be overwritten.
+#[derive(Debug, Clone)]
+pub struct Env {
+    vars: HashMap<String, f64>,
+}
+
+impl Env {
+    /// Creates an environment containing only the constants `pi` and `e`
+    pub fn new() -> Self {
+        let mut vars = HashMap::new();
+        vars.insert("pi".to_string(), std::f64::consts::PI);
+        vars.insert("e".to_string(), std::f64::consts::E);
+        Self { vars }
+    }
+
+    /// Binds `name` to `value`, replacing any previous binding
+    pub fn set(&mut self, name: &str, value: f64) {
+        self.vars.insert(name.to_string(), value);
+    }
+
+    /// Returns the value bound to `name`
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.vars.get(name).copied()
+    }
+}
+
+impl Default for Env {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A built-in function's implementation, given exactly `arity` arguments
+type Builtin = fn(&[f64]) -> f64;
+
+/// Looks up a built-in function: (arity, implementation)
+fn builtin(name: &str) -> Option<(usize, Builtin)> {
+    let entry: (usize, Builtin) = match name {
+        "abs" => (1, |a| a[0].abs()),
+        "sqrt" => (1, |a| a[0].sqrt()),
+        "exp" => (1, |a| a[0].exp()),
+        "ln" => (1, |a| a[0].ln()),
+        "sin" => (1, |a| a[0].sin()),
+        "cos" => (1, |a| a[0].cos()),
+        "tan" => (1, |a| a[0].tan()),
+        "floor" => (1, |a| a[0].floor()),
+        "ceil" => (1, |a| a[0].ceil()),
+        "min" => (2, |a| a[0].min(a[1])),
+        "max" => (2, |a| a[0].max(a[1])),
+        _ => return None,
+    };
+    Some(entry)
+}
+
+/// Splits infix text into tokens paired with their byte index (REQ-E1)
+///
+/// # Examples
+/// ```
+/// use mission1::expr::{tokenize, Op, Token};
+///
+/// let tokens = tokenize("x*2.5").unwrap();
+/// assert_eq!(tokens, vec![
+///     (0, Token::Ident("x".to_string())),
+///     (1, Token::Op(Op::Mul)),
+///     (2, Token::Number(2.5)),
+/// ]);
+/// ```
+pub fn tokenize(src: &str) -> Result<Vec<(usize, Token)>, ExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = src.char_indices().peekable();
+
+    while let Some(&(i, ch)) = chars.peek() {
+        let token = if ch.is_whitespace() {
+            chars.next();
+            continue;
+        } else if ch.is_ascii_digit() || ch == '.' {
+            let mut end = i;
+            while let Some(&(j, c)) = chars.peek() {
+                if !(c.is_ascii_digit() || c == '.') {
+                    break;
+                }
+                end = j + c.len_utf8();
+                chars.next();
+            }
+            let value = src[i..end].parse().map_err(|_| ExprError::new(i, ExprErrorKind::InvalidNumber))?;
+            Token::Number(value)
+        } else if ch.is_alphabetic() || ch == '_' {
+            let mut end = i;
+            while let Some(&(j, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = j + c.len_utf8();
+                chars.next();
+            }
+            Token::Ident(src[i..end].to_string())
+        } else {
+            chars.next();
+            match ch {
+                '+' => Token::Op(Op::Add),
+                '-' => Token::Op(Op::Sub),
+                '*' => Token::Op(Op::Mul),
+                '/' => Token::Op(Op::Div),
+                '%' => Token::Op(Op::Rem),
+                '^' => Token::Op(Op::Pow),
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                ',' => Token::Comma,
+                found => return Err(ExprError::new(i, ExprErrorKind::UnexpectedChar { found })),
+            }
+        };
+        tokens.push((i, token));
+    }
+    Ok(tokens)
+}
+
+/// Entries waiting on the shunting-yard operator stack
+enum Pending {
+    Op { index: usize, op: Op },
+    /// An open parenthesis; `func` holds the (index, name) of the call it starts, if any
+    Open { index: usize, func: Option<(usize, String)>, commas: usize },
+}
+
+/// Moves operators from the pending stack to the output until a `(` or the bottom
+fn flush_operators(pending: &mut Stack<Pending>, output: &mut Vec<(usize, RpnItem)>) {
+    while let Some(&Pending::Op { index, op }) = pending.peek() {
+        pending.pop();
+        output.push((index, RpnItem::Op(op)));
+    }
+}
+
+/// Converts infix text to an RPN program with the shunting-yard algorithm (REQ-E2, REQ-E3)
+///
+/// Each item keeps the byte index of the token it came from, so evaluation
+/// errors can still point into the original text. A name directly followed by
+/// `(` is a function call; any other name is a variable.
+///
+/// # Examples
+/// ```
+/// use mission1::expr::to_rpn;
+///
+/// let rpn = to_rpn("3 + 4 * 2 / (1 - 5) ^ 2 ^ 3").unwrap();
+/// let text: Vec<String> = rpn.iter().map(|(_, item)| item.to_string()).collect();
+/// assert_eq!(text.join(" "), "3 4 2 * 1 5 - 2 3 ^ ^ / +");
+/// ```
+pub fn to_rpn(src: &str) -> Result<Vec<(usize, RpnItem)>, ExprError> {
+    let mut tokens = tokenize(src)?.into_iter().peekable();
+    let mut output = Vec::new();
+    let mut pending: Stack<Pending> = Stack::new();
+    // True where an operand (number, name, `(` or unary minus) must come next
+    let mut expect_operand = true;
+    // True right after a `(`, where `)` may close an empty argument list
+    let mut after_open = false;
+
+    while let Some((i, token)) = tokens.next() {
+        let opens = match &token {
+            Token::LParen => true,
+            Token::Ident(_) => matches!(tokens.peek(), Some((_, Token::LParen))),
+            _ => false,
+        };
+        match token {
+            Token::Number(_) | Token::Ident(_) | Token::LParen if !expect_operand => {
+                return Err(ExprError::new(i, ExprErrorKind::ExpectedOperator));
+            }
+            Token::Number(x) => {
+                output.push((i, RpnItem::Number(x)));
+                expect_operand = false;
+            }
+            Token::Ident(name) if opens => {
+                let (open, _) = tokens.next().expect("peeked `(`");
+                pending.push(Pending::Open { index: open, func: Some((i, name)), commas: 0 });
+            }
+            Token::Ident(name) => {
+                output.push((i, RpnItem::Var(name)));
+                expect_operand = false;
+            }
+            Token::LParen => pending.push(Pending::Open { index: i, func: None, commas: 0 }),
+            Token::RParen if after_open && matches!(pending.peek(), Some(Pending::Open { func: Some(_), .. })) => {
+                // `f()`: a call with no arguments
+                if let Some(Pending::Open { func: Some((at, name)), .. }) = pending.pop() {
+                    output.push((at, RpnItem::Call { name, argc: 0 }));
+                }
+                expect_operand = false;
+            }
+            Token::Op(Op::Sub) if expect_operand => pending.push(Pending::Op { index: i, op: Op::Neg }),
+            Token::Op(_) | Token::RParen | Token::Comma if expect_operand => {
+                return Err(ExprError::new(i, ExprErrorKind::MissingOperand));
+            }
+            Token::Op(op) => {
+                while let Some(&Pending::Op { index, op: top }) = pending.peek() {
+                    let pops = top.precedence() > op.precedence()
+                        || (top.precedence() == op.precedence() && !op.is_right_assoc());
+                    if !pops {
+                        break;
+                    }
+                    pending.pop();
+                    output.push((index, RpnItem::Op(top)));
+                }
+                pending.push(Pending::Op { index: i, op });
+                expect_operand = true;
+            }
+            Token::Comma => {
+                flush_operators(&mut pending, &mut output);
+                match pending.peek_mut() {
+                    Some(Pending::Open { func: Some(_), commas, .. }) => *commas += 1,
+                    _ => return Err(ExprError::new(i, ExprErrorKind::MisplacedComma)),
+                }
+                expect_operand = true;
+            }
+            Token::RParen => {
+                flush_operators(&mut pending, &mut output);
+                match pending.pop() {
+                    Some(Pending::Open { func: Some((at, name)), commas, .. }) => {
+                        output.push((at, RpnItem::Call { name, argc: commas + 1 }));
+                    }
+                    Some(Pending::Open { func: None, .. }) => {}
+                    _ => return Err(ExprError::new(i, ExprErrorKind::UnexpectedClosing)),
+                }
+            }
+        }
+        after_open = opens;
+    }
+
+    if expect_operand {
+        return Err(ExprError::new(src.len(), ExprErrorKind::MissingOperand));
+    }
+    while let Some(entry) = pending.pop() {
+        match entry {
+            Pending::Op { index, op } => output.push((index, RpnItem::Op(op))),
+            Pending::Open { index, .. } => {
+                return Err(ExprError::new(index, ExprErrorKind::UnclosedParen { open_index: index }));
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// Parses whitespace-separated postfix text such as `"3 4 + neg"`
+///
+/// Numbers push constants, `+ - * / % ^` are binary operators, `neg` is unary
+/// minus, built-in function names are calls with their fixed arity and any
+/// other name is a variable.
+///
+/// # Examples
+/// ```
+/// use mission1::expr::{eval_rpn, parse_rpn, Env};
+///
+/// let rpn = parse_rpn("2 3 max 4 * neg").unwrap();
+/// assert_eq!(eval_rpn(&rpn, &Env::new()), Ok(-12.0));
+/// ```
+pub fn parse_rpn(src: &str) -> Result<Vec<(usize, RpnItem)>, ExprError> {
+    tokenize(src)?
+        .into_iter()
+        .map(|(i, token)| {
+            let item = match token {
+                Token::Number(x) => RpnItem::Number(x),
+                Token::Op(op) => RpnItem::Op(op),
+                Token::Ident(name) if name == "neg" => RpnItem::Op(Op::Neg),
+                Token::Ident(name) => match builtin(&name) {
+                    Some((argc, _)) => RpnItem::Call { name, argc },
+                    None => RpnItem::Var(name),
+                },
+                Token::LParen => return Err(ExprError::new(i, ExprErrorKind::UnexpectedChar { found: '(' })),
+                Token::RParen => return Err(ExprError::new(i, ExprErrorKind::UnexpectedChar { found: ')' })),
+                Token::Comma => return Err(ExprError::new(i, ExprErrorKind::UnexpectedChar { found: ',' })),
+            };
+            Ok((i, item))
+        })
+        .collect()
+}
+
+/// Evaluates an RPN program on a [`Stack`] of operands (REQ-E4)
+///
+/// # Returns
+/// * `Ok(value)` - The single value left on the stack
+/// * `Err(ExprError)` - The first failure, positioned at the offending item
+///   (or at index 0 for an empty program)
+///
+/// # Examples
+/// ```
+/// use mission1::expr::{eval_rpn, to_rpn, Env};
+///
+/// let mut env = Env::new();
+/// env.set("r", 2.0);
+/// let area = to_rpn("pi * r ^ 2").unwrap();
+/// assert_eq!(eval_rpn(&area, &env), Ok(std::f64::consts::PI * 4.0));
+/// ```
+pub fn eval_rpn(rpn: &[(usize, RpnItem)], env: &Env) -> Result<f64, ExprError> {
+    let mut operands: Stack<f64> = Stack::new();
+
+    for (i, item) in rpn {
+        let i = *i;
+        let value = match item {
+            RpnItem::Number(x) => *x,
+            RpnItem::Var(name) => env
+                .get(name)
+                .ok_or_else(|| ExprError::new(i, ExprErrorKind::UnknownVariable { name: name.clone() }))?,
+            RpnItem::Op(Op::Neg) => {
+                let x = operands.pop().ok_or(ExprError::new(i, ExprErrorKind::MissingOperand))?;
+                -x
+            }
+            RpnItem::Op(op) => {
+                // The right-hand operand is on top
+                let rhs = operands.pop();
+                let lhs = operands.pop();
+                let (Some(lhs), Some(rhs)) = (lhs, rhs) else {
+                    return Err(ExprError::new(i, ExprErrorKind::MissingOperand));
+                };
+                match op {
+                    Op::Add => lhs + rhs,
+                    Op::Sub => lhs - rhs,
+                    Op::Mul => lhs * rhs,
+                    Op::Div | Op::Rem if rhs == 0.0 => {
+                        return Err(ExprError::new(i, ExprErrorKind::DivisionByZero));
+                    }
+                    Op::Div => lhs / rhs,
+                    Op::Rem => lhs % rhs,
+                    Op::Pow => lhs.powf(rhs),
+                    Op::Neg => unreachable!("handled above"),
+                }
+            }
+            RpnItem::Call { name, argc } => {
+                let (arity, f) = builtin(name)
+                    .ok_or_else(|| ExprError::new(i, ExprErrorKind::UnknownFunction { name: name.clone() }))?;
+                if *argc != arity {
+                    return Err(ExprError::new(
+                        i,
+                        ExprErrorKind::WrongArity { name: name.clone(), expected: arity, found: *argc },
+                    ));
+                }
+                if operands.len() < arity {
+                    return Err(ExprError::new(i, ExprErrorKind::MissingOperand));
+                }
+                // Popping yields the arguments last-first; restore call order
+                let mut args: Vec<f64> = (0..arity).filter_map(|_| operands.pop()).collect();
+                args.reverse();
+                f(&args)
+            }
+        };
+        operands.push(value);
+    }
+
+    match operands.len() {
+        1 => Ok(operands.pop().expect("length checked")),
+        0 => Err(ExprError::new(rpn.last().map_or(0, |(i, _)| *i), ExprErrorKind::MissingOperand)),
+        count => Err(ExprError::new(rpn.last().map_or(0, |(i, _)| *i), ExprErrorKind::LeftoverOperands { count })),
+    }
+}
+
+/// Tokenizes, converts and evaluates an infix expression in one call
+///
+/// # Examples
+/// ```
+/// use mission1::expr::{eval, Env};
+///
+/// let mut env = Env::new();
+/// env.set("x", 3.0);
+/// assert_eq!(eval("-x^2 + max(x, 10) / 2", &env), Ok(-4.0));
+/// assert_eq!(eval("2^3^2", &env), Ok(512.0)); // Right-associative
+/// ```
+pub fn eval(src: &str, env: &Env) -> Result<f64, ExprError> {
+    eval_rpn(&to_rpn(src)?, env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpn_text(src: &str) -> String {
+        let rpn = to_rpn(src).unwrap();
+        rpn.iter().map(|(_, item)| item.to_string()).collect::<Vec<_>>().join(" ")
+    }
+
+    fn error_at(src: &str) -> (usize, ExprErrorKind) {
+        let err = eval(src, &Env::new()).unwrap_err();
+        (err.index, err.kind)
+    }
+
+    #[test] // REQ-E1
+    fn tokens_carry_byte_indices() {
+        let tokens = tokenize("  αβ + 10").unwrap();
+        assert_eq!(tokens[0], (2, Token::Ident("αβ".to_string())));
+        assert_eq!(tokens[1], (7, Token::Op(Op::Add)));
+        assert_eq!(tokens[2], (9, Token::Number(10.0)));
+    }
+
+    #[test] // REQ-E2
+    fn precedence_and_associativity() {
+        assert_eq!(rpn_text("1 - 2 - 3"), "1 2 - 3 -");
+        assert_eq!(rpn_text("2 ^ 3 ^ 2"), "2 3 2 ^ ^");
+        assert_eq!(rpn_text("1 + 2 * 3 % 4"), "1 2 3 * 4 % +");
+        assert_eq!(rpn_text("(1 + 2) * 3"), "1 2 + 3 *");
+
+        let env = Env::new();
+        assert_eq!(eval("10 - 4 - 3", &env), Ok(3.0));
+        assert_eq!(eval("64 / 4 / 2", &env), Ok(8.0));
+        assert_eq!(eval("2 ^ 3 ^ 2", &env), Ok(512.0));
+    }
+
+    #[test] // REQ-E3
+    fn unary_minus_binds_looser_than_power() {
+        assert_eq!(rpn_text("-2 ^ 2"), "2 2 ^ neg");
+        assert_eq!(rpn_text("2 ^ -1"), "2 1 neg ^");
+        let env = Env::new();
+        assert_eq!(eval("-2 ^ 2", &env), Ok(-4.0));
+        assert_eq!(eval("2 * -3", &env), Ok(-6.0));
+        assert_eq!(eval("--3", &env), Ok(3.0));
+        assert_eq!(eval("-(1 + 2) * 2", &env), Ok(-6.0));
+    }
+
+    #[test] // REQ-E3
+    fn functions_and_variables() {
+        let mut env = Env::new();
+        env.set("x", 9.0);
+        env.set("y_2", -1.5);
+        assert_eq!(eval("sqrt(x) + abs(y_2)", &env), Ok(4.5));
+        assert_eq!(eval("max(min(x, 4), 2 * 3) - 1", &env), Ok(5.0));
+        assert_eq!(eval("floor(cos(0) + 0.5)", &env), Ok(1.0));
+        assert_eq!(rpn_text("max(1, 2 + 3)"), "1 2 3 + max");
+    }
+
+    #[test] // REQ-E4
+    fn evaluates_postfix_text() {
+        let env = Env::new();
+        let rpn = parse_rpn("5 1 2 + 4 * + 3 -").unwrap();
+        assert_eq!(eval_rpn(&rpn, &env), Ok(14.0));
+        assert_eq!(
+            eval_rpn(&parse_rpn("1 2").unwrap(), &env).unwrap_err().kind,
+            ExprErrorKind::LeftoverOperands { count: 2 }
+        );
+        assert_eq!(eval_rpn(&parse_rpn("1 +").unwrap(), &env).unwrap_err().index, 2);
+    }
+
+    #[test] // REQ-E5
+    fn syntax_errors_point_at_the_problem() {
+        assert_eq!(error_at("2 $ 3"), (2, ExprErrorKind::UnexpectedChar { found: '$' }));
+        assert_eq!(error_at("1.2.3"), (0, ExprErrorKind::InvalidNumber));
+        assert_eq!(error_at("2 3"), (2, ExprErrorKind::ExpectedOperator));
+        assert_eq!(error_at("2 * * 3"), (4, ExprErrorKind::MissingOperand));
+        assert_eq!(error_at("2 +"), (3, ExprErrorKind::MissingOperand));
+        assert_eq!(error_at("(1 + 2))"), (7, ExprErrorKind::UnexpectedClosing));
+        assert_eq!(error_at("1 + (2 * (3)"), (4, ExprErrorKind::UnclosedParen { open_index: 4 }));
+        assert_eq!(error_at("(1, 2)"), (2, ExprErrorKind::MisplacedComma));
+        assert_eq!(error_at("()"), (1, ExprErrorKind::MissingOperand));
+    }
+
+    #[test] // REQ-E5
+    fn evaluation_errors_point_at_the_problem() {
+        assert_eq!(error_at("1 + nope"), (4, ExprErrorKind::UnknownVariable { name: "nope".to_string() }));
+        assert_eq!(error_at("2 * foo(1)"), (4, ExprErrorKind::UnknownFunction { name: "foo".to_string() }));
+        assert_eq!(
+            error_at("max(1)"),
+            (0, ExprErrorKind::WrongArity { name: "max".to_string(), expected: 2, found: 1 })
+        );
+        assert_eq!(
+            error_at("sqrt()"),
+            (0, ExprErrorKind::WrongArity { name: "sqrt".to_string(), expected: 1, found: 0 })
+        );
+        assert_eq!(error_at("1 / (2 - 2)"), (2, ExprErrorKind::DivisionByZero));
+    }
+}
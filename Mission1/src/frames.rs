@@ -0,0 +1,446 @@
+// Call frames for a small stack-based interpreter
+//
+// A VM needs two kinds of stack: the call stack (one frame per active
+// function) and, inside every frame, an operand stack for intermediate
+// values like the one eval_rpn uses. FrameStack keeps both: each Frame owns
+// its local slots and its own Stack<T> of operands, so a callee can never
+// pop values that belong to its caller. Every error carries a Backtrace of
+// the frames that were active when it happened.
+//
+// expr::eval_rpn does not run on FrameStack: expressions have no function
+// calls, so it keeps its single Stack<f64> and ExprError positions. The tests
+// below drive parse_rpn output through FrameStack to show how an interpreter
+// with calls would build on it.
+//
+// REQ-V1: push_frame / pop_frame shall open and close frames with a fixed number of local slots
+// REQ-V2: Local slots shall be read and written by index, with out-of-range access reported
+// REQ-V3: Each frame shall have its own operand stack; call / ret move arguments and results
+// REQ-V4: Exceeding a configurable maximum depth shall report a stack overflow
+// REQ-V5: Errors shall carry a backtrace (innermost frame first, with its pc)
+
+use crate::Stack;
+use std::fmt;
+
+/// Maximum number of frames used by [`FrameStack::new`]
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// One activation record: a name, a program counter, locals and operands
+#[derive(Debug)]
+pub struct Frame<T> {
+    name: String,
+    pc: usize,
+    locals: Vec<T>,
+    operands: Stack<T>,
+}
+
+impl<T> Frame<T> {
+    /// The function name given when the frame was pushed
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The position last recorded with [`FrameStack::set_pc`]
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// The local slots, in index order
+    pub fn locals(&self) -> &[T] {
+        &self.locals
+    }
+
+    /// The frame's operand stack
+    pub fn operands(&self) -> &Stack<T> {
+        &self.operands
+    }
+
+    /// Consumes the frame and returns its operand stack
+    pub fn into_operands(self) -> Stack<T> {
+        self.operands
+    }
+}
+
+/// A frame as it appears in a [`Backtrace`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameInfo {
+    /// Function name
+    pub name: String,
+    /// Program counter at the time the backtrace was taken
+    pub pc: usize,
+}
+
+/// The active frames when an error occurred, innermost first (REQ-V5)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Backtrace {
+    /// `frames[0]` is the frame that was executing
+    pub frames: Vec<FrameInfo>,
+}
+
+impl fmt::Display for Backtrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (depth, frame) in self.frames.iter().enumerate() {
+            writeln!(f, "  #{} {} at pc {}", depth, frame.name, frame.pc)?;
+        }
+        Ok(())
+    }
+}
+
+/// The different ways a frame operation can fail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameErrorKind {
+    /// Pushing another frame would exceed the configured depth (REQ-V4)
+    StackOverflow { max_depth: usize },
+    /// The operation needs a current frame but none is active
+    NoFrame,
+    /// A local slot index past the frame's `n_locals`
+    LocalOutOfRange { index: usize, n_locals: usize },
+    /// The current frame's operand stack has fewer values than needed
+    OperandUnderflow,
+}
+
+/// A frame error together with the backtrace at the point of failure (REQ-V5)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameError {
+    /// What went wrong
+    pub kind: FrameErrorKind,
+    /// Active frames when it went wrong
+    pub backtrace: Backtrace,
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:?}", self.kind)?;
+        write!(f, "{}", self.backtrace)
+    }
+}
+
+/// A call stack of [`Frame`]s, each with its own locals and operand stack
+///
+/// # Examples
+/// ```
+/// use mission1::frames::{FrameErrorKind, FrameStack};
+///
+/// /// fact(n) = n * fact(n - 1), with n in local 0
+/// fn fact(vm: &mut FrameStack<u64>) -> Result<(), mission1::frames::FrameError> {
+///     let n = *vm.local(0)?;
+///     vm.set_pc(1);
+///     if n <= 1 {
+///         vm.push(1)?;
+///     } else {
+///         vm.push(n - 1)?;
+///         vm.call("fact", 1, 1)?;
+///         fact(vm)?;
+///         vm.ret()?; // The callee's result lands on our operand stack
+///         let sub = vm.pop()?;
+///         vm.push(n * sub)?;
+///     }
+///     Ok(())
+/// }
+///
+/// let mut vm = FrameStack::new();
+/// vm.push_frame("main", 0).unwrap();
+/// vm.push(10).unwrap();
+/// vm.call("fact", 1, 1).unwrap();
+/// fact(&mut vm).unwrap();
+/// vm.ret().unwrap();
+/// assert_eq!(vm.pop(), Ok(3_628_800));
+///
+/// // The same recursion overflows a shallow stack, with a backtrace
+/// let mut shallow = FrameStack::with_max_depth(4);
+/// shallow.push_frame("main", 0).unwrap();
+/// shallow.push(10).unwrap();
+/// shallow.call("fact", 1, 1).unwrap();
+/// let err = fact(&mut shallow).unwrap_err();
+/// assert_eq!(err.kind, FrameErrorKind::StackOverflow { max_depth: 4 });
+/// assert_eq!(err.backtrace.frames.len(), 4);
+/// assert_eq!(err.backtrace.frames[3].name, "main");
+/// ```
+#[derive(Debug)]
+pub struct FrameStack<T> {
+    frames: Stack<Frame<T>>,
+    max_depth: usize,
+}
+
+impl<T> FrameStack<T> {
+    /// Creates an empty call stack allowing [`DEFAULT_MAX_DEPTH`] frames
+    pub fn new() -> Self {
+        Self::with_max_depth(DEFAULT_MAX_DEPTH)
+    }
+
+    /// Creates an empty call stack allowing at most `max_depth` frames (REQ-V4)
+    ///
+    /// # Panics
+    /// Panics if `max_depth` is 0
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        assert!(max_depth > 0, "max depth must be > 0");
+        Self { frames: Stack::new(), max_depth }
+    }
+
+    /// Closes the current frame and returns it (REQ-V1)
+    pub fn pop_frame(&mut self) -> Result<Frame<T>, FrameError> {
+        self.frames.pop().ok_or_else(|| self.error(FrameErrorKind::NoFrame))
+    }
+
+    /// Returns from the current frame, handing its top operand to the caller (REQ-V3)
+    ///
+    /// # Returns
+    /// * `Ok(None)` - The result was pushed onto the caller's operand stack
+    /// * `Ok(Some(value))` - The outermost frame returned; `value` is the program's result
+    /// * `Err(FrameError)` - No frame is active, or the frame has no value to return
+    pub fn ret(&mut self) -> Result<Option<T>, FrameError> {
+        let value = self.current_mut()?.operands.pop();
+        let Some(value) = value else {
+            return Err(self.error(FrameErrorKind::OperandUnderflow));
+        };
+        self.frames.pop();
+        match self.frames.peek_mut() {
+            Some(caller) => {
+                caller.operands.push(value);
+                Ok(None)
+            }
+            None => Ok(Some(value)),
+        }
+    }
+
+    /// Returns local slot `index` of the current frame (REQ-V2)
+    pub fn local(&self, index: usize) -> Result<&T, FrameError> {
+        let frame = self.frames.peek().ok_or_else(|| self.error(FrameErrorKind::NoFrame))?;
+        frame
+            .locals
+            .get(index)
+            .ok_or_else(|| self.error(FrameErrorKind::LocalOutOfRange { index, n_locals: frame.locals.len() }))
+    }
+
+    /// Overwrites local slot `index` of the current frame (REQ-V2)
+    pub fn set_local(&mut self, index: usize, value: T) -> Result<(), FrameError> {
+        let n_locals = self.current_mut()?.locals.len();
+        if index >= n_locals {
+            return Err(self.error(FrameErrorKind::LocalOutOfRange { index, n_locals }));
+        }
+        self.current_mut()?.locals[index] = value;
+        Ok(())
+    }
+
+    /// Pushes onto the current frame's operand stack (REQ-V3)
+    pub fn push(&mut self, value: T) -> Result<(), FrameError> {
+        self.current_mut()?.operands.push(value);
+        Ok(())
+    }
+
+    /// Pops from the current frame's operand stack; never reaches into the caller (REQ-V3)
+    pub fn pop(&mut self) -> Result<T, FrameError> {
+        match self.current_mut()?.operands.pop() {
+            Some(value) => Ok(value),
+            None => Err(self.error(FrameErrorKind::OperandUnderflow)),
+        }
+    }
+
+    /// Returns the top of the current frame's operand stack
+    pub fn peek(&self) -> Option<&T> {
+        self.frames.peek()?.operands.peek()
+    }
+
+    /// Records the current frame's position, as reported in backtraces (REQ-V5)
+    pub fn set_pc(&mut self, pc: usize) {
+        if let Some(frame) = self.frames.peek_mut() {
+            frame.pc = pc;
+        }
+    }
+
+    /// Returns the executing frame
+    pub fn current(&self) -> Option<&Frame<T>> {
+        self.frames.peek()
+    }
+
+    /// Returns the number of active frames
+    pub fn depth(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the configured maximum number of frames
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns true if no frame is active
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Snapshot of the active frames, innermost first (REQ-V5)
+    pub fn backtrace(&self) -> Backtrace {
+        let frames = self.frames.iter().map(|f| FrameInfo { name: f.name.clone(), pc: f.pc }).collect();
+        Backtrace { frames }
+    }
+
+    fn current_mut(&mut self) -> Result<&mut Frame<T>, FrameError> {
+        if self.frames.is_empty() {
+            return Err(self.error(FrameErrorKind::NoFrame));
+        }
+        Ok(self.frames.peek_mut().expect("checked non-empty"))
+    }
+
+    /// `Err` with [`FrameErrorKind::StackOverflow`] if no further frame fits (REQ-V4)
+    fn check_depth(&self) -> Result<(), FrameError> {
+        if self.frames.len() == self.max_depth {
+            return Err(self.error(FrameErrorKind::StackOverflow { max_depth: self.max_depth }));
+        }
+        Ok(())
+    }
+
+    fn error(&self, kind: FrameErrorKind) -> FrameError {
+        FrameError { kind, backtrace: self.backtrace() }
+    }
+}
+
+impl<T: Default> FrameStack<T> {
+    /// Opens a frame named `name` with `n_locals` slots set to `T::default()` (REQ-V1, REQ-V4)
+    ///
+    /// # Returns
+    /// `Err` with [`FrameErrorKind::StackOverflow`] if `max_depth` frames are already active
+    pub fn push_frame(&mut self, name: &str, n_locals: usize) -> Result<(), FrameError> {
+        self.check_depth()?;
+        let locals = std::iter::repeat_with(T::default).take(n_locals).collect();
+        self.frames.push(Frame { name: name.to_string(), pc: 0, locals, operands: Stack::new() });
+        Ok(())
+    }
+
+    /// Calls `name`: moves the top `argc` operands into the new frame's first locals (REQ-V3)
+    ///
+    /// Arguments keep their push order, so the first one pushed becomes local 0.
+    /// Nothing changes if the caller has fewer than `argc` operands or the
+    /// stack is full.
+    ///
+    /// # Panics
+    /// Panics if `argc > n_locals`
+    pub fn call(&mut self, name: &str, argc: usize, n_locals: usize) -> Result<(), FrameError> {
+        assert!(argc <= n_locals, "argc {} exceeds n_locals {}", argc, n_locals);
+        if self.current_mut()?.operands.len() < argc {
+            return Err(self.error(FrameErrorKind::OperandUnderflow));
+        }
+        self.check_depth()?;
+        let caller = self.current_mut()?;
+        // pop_n yields the last argument first
+        let mut locals: Vec<T> = caller.operands.pop_n(argc).expect("length checked").into_iter().rev().collect();
+        locals.resize_with(n_locals, T::default);
+        self.frames.push(Frame { name: name.to_string(), pc: 0, locals, operands: Stack::new() });
+        Ok(())
+    }
+}
+
+impl<T> Default for FrameStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{parse_rpn, Op, RpnItem};
+
+    #[test] // REQ-V1, REQ-V2
+    fn frames_own_their_locals() {
+        let mut vm: FrameStack<i32> = FrameStack::new();
+        assert_eq!(vm.local(0).unwrap_err().kind, FrameErrorKind::NoFrame);
+
+        vm.push_frame("main", 2).unwrap();
+        vm.set_local(1, 7).unwrap();
+        vm.push_frame("helper", 1).unwrap();
+        assert_eq!(vm.local(0), Ok(&0));
+        assert_eq!(
+            vm.set_local(1, 3).unwrap_err().kind,
+            FrameErrorKind::LocalOutOfRange { index: 1, n_locals: 1 }
+        );
+
+        let helper = vm.pop_frame().unwrap();
+        assert_eq!((helper.name(), helper.locals()), ("helper", &[0][..]));
+        assert_eq!(vm.local(1), Ok(&7));
+        vm.pop_frame().unwrap();
+        assert!(vm.is_empty());
+        assert_eq!(vm.pop_frame().unwrap_err().kind, FrameErrorKind::NoFrame);
+    }
+
+    #[test] // REQ-V3
+    fn operand_stacks_are_isolated_per_frame() {
+        let mut vm = FrameStack::new();
+        vm.push_frame("main", 0).unwrap();
+        vm.push(100).unwrap();
+        vm.push(8).unwrap();
+        vm.push(2).unwrap();
+
+        // sub(a, b) = a - b
+        vm.call("sub", 2, 3).unwrap();
+        assert_eq!(vm.local(0), Ok(&8));
+        assert_eq!(vm.local(1), Ok(&2));
+        assert_eq!(vm.pop().unwrap_err().kind, FrameErrorKind::OperandUnderflow);
+        let (a, b) = (*vm.local(0).unwrap(), *vm.local(1).unwrap());
+        vm.push(a - b).unwrap();
+        assert_eq!(vm.ret(), Ok(None));
+
+        assert_eq!(vm.current().unwrap().operands().iter().copied().collect::<Vec<_>>(), vec![6, 100]);
+        assert_eq!(vm.call("f", 3, 3).unwrap_err().kind, FrameErrorKind::OperandUnderflow);
+        assert_eq!(vm.depth(), 1); // The failed call left nothing behind
+        vm.pop().unwrap();
+        assert_eq!(vm.ret(), Ok(Some(100)));
+    }
+
+    #[test] // REQ-V4, REQ-V5
+    fn overflow_reports_a_backtrace() {
+        let mut vm: FrameStack<u8> = FrameStack::with_max_depth(3);
+        for (pc, name) in ["main", "parse", "expr"].into_iter().enumerate() {
+            vm.push_frame(name, 0).unwrap();
+            vm.set_pc(pc * 10);
+        }
+        let err = vm.push_frame("term", 0).unwrap_err();
+        assert_eq!(err.kind, FrameErrorKind::StackOverflow { max_depth: 3 });
+        assert_eq!(err.backtrace.to_string(), "  #0 expr at pc 20\n  #1 parse at pc 10\n  #2 main at pc 0\n");
+        assert_eq!(vm.depth(), 3);
+
+        // A call that would overflow leaves its arguments with the caller
+        vm.push(7).unwrap();
+        assert_eq!(vm.call("term", 1, 1).unwrap_err().kind, FrameErrorKind::StackOverflow { max_depth: 3 });
+        assert_eq!((vm.depth(), vm.peek()), (3, Some(&7)));
+    }
+
+    #[test] // REQ-V3, REQ-V5: an RPN program with a call, run on frames
+    fn runs_rpn_with_positions_in_backtrace() {
+        // Runs `rpn` in a frame named `name`; `sq` calls a frame computing x * x
+        fn run(vm: &mut FrameStack<f64>, rpn: &[(usize, RpnItem)]) -> Result<(), FrameError> {
+            for (i, item) in rpn {
+                vm.set_pc(*i);
+                match item {
+                    RpnItem::Number(x) => vm.push(*x)?,
+                    RpnItem::Var(name) if name == "sq" => {
+                        vm.call("sq", 1, 1)?;
+                        let x = *vm.local(0)?;
+                        vm.push(x * x)?;
+                        vm.ret()?;
+                    }
+                    RpnItem::Op(Op::Div) => {
+                        let rhs = vm.pop()?;
+                        let lhs = vm.pop()?;
+                        vm.push(lhs / rhs)?;
+                    }
+                    RpnItem::Op(Op::Add) => {
+                        let rhs = vm.pop()?;
+                        let lhs = vm.pop()?;
+                        vm.push(lhs + rhs)?;
+                    }
+                    other => panic!("unsupported item {}", other),
+                }
+            }
+            Ok(())
+        }
+
+        let mut vm = FrameStack::new();
+        vm.push_frame("main", 0).unwrap();
+        run(&mut vm, &parse_rpn("3 sq 4 sq + 5 /").unwrap()).unwrap();
+        assert_eq!(vm.ret(), Ok(Some(5.0)));
+
+        vm.push_frame("main", 0).unwrap();
+        let err = run(&mut vm, &parse_rpn("1 + sq").unwrap()).unwrap_err();
+        assert_eq!(err.kind, FrameErrorKind::OperandUnderflow);
+        assert_eq!(err.backtrace.frames, vec![FrameInfo { name: "main".to_string(), pc: 2 }]);
+    }
+}
@@ -0,0 +1,261 @@
+// A small-buffer-optimised stack with inline storage
+//
+// Stack::new() is cheap, but the first push always allocates. Bracket checks
+// usually nest only a few levels deep, so most stacks never need the heap at
+// all. InlineStack<T, N> keeps its first N elements in an array inside the
+// struct and only allocates an overflow Vec once the (N+1)-th element arrives.
+//
+// REQ-I1: The first N elements shall be stored inline with no heap allocation
+// REQ-I2: Pushes beyond N shall spill to the heap transparently (amortized O(1))
+// REQ-I3: Inline elements shall never move and shall be dropped exactly once
+
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// A LIFO stack storing up to `N` elements inline before touching the heap
+///
+/// # Type Parameters
+/// * `T` - The type of elements stored in the stack
+/// * `N` - Number of elements kept inline (chosen at compile time)
+///
+/// # Memory Layout
+/// ```text
+/// inline:   [e0, e1, ..., e(N-1)]   always filled bottom-up first
+/// overflow: Vec [eN, e(N+1), ...]   only allocated once inline is full
+/// ```
+/// The overflow `Vec` is non-empty only while the inline buffer is full, so the
+/// top of the stack is the last overflow element if there is one, otherwise
+/// the last inline element.
+///
+/// # Examples
+/// ```
+/// use mission1::InlineStack;
+///
+/// let mut stack: InlineStack<char, 4> = InlineStack::new();
+/// for ch in "([{<".chars() {
+///     stack.push(ch);
+/// }
+/// assert!(!stack.spilled()); // 4 elements fit inline - no allocation
+///
+/// stack.push('(');
+/// assert!(stack.spilled());  // The 5th went to the heap
+/// assert_eq!(stack.pop(), Some('('));
+/// ```
+pub struct InlineStack<T, const N: usize> {
+    /// Slots `0..inline_len` are initialized
+    inline: [MaybeUninit<T>; N],
+    inline_len: usize,
+    /// Elements beyond the first N; empty (and unallocated) until a spill happens
+    overflow: Vec<T>,
+}
+
+impl<T, const N: usize> InlineStack<T, N> {
+    /// Creates an empty stack; never allocates (REQ-I1)
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::InlineStack;
+    /// let stack: InlineStack<u32, 8> = InlineStack::new();
+    /// assert!(stack.is_empty());
+    /// assert_eq!(stack.inline_capacity(), 8);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            inline: [const { MaybeUninit::uninit() }; N],
+            inline_len: 0,
+            overflow: Vec::new(),
+        }
+    }
+
+    /// Pushes a value onto the top of the stack (REQ-I1, REQ-I2)
+    ///
+    /// O(1) while inline; amortized O(1) once spilled (delegates to `Vec::push`).
+    pub fn push(&mut self, x: T) {
+        if self.inline_len < N {
+            self.inline[self.inline_len].write(x);
+            self.inline_len += 1;
+        } else {
+            self.overflow.push(x); // REQ-I2: spill
+        }
+    }
+
+    /// Pops and returns the top value (O(1), ownership is transferred)
+    pub fn pop(&mut self) -> Option<T> {
+        if let Some(x) = self.overflow.pop() {
+            return Some(x);
+        }
+        if self.inline_len == 0 {
+            return None;
+        }
+        self.inline_len -= 1;
+        // SAFETY: slot `inline_len` was initialized and is now logically removed,
+        // so it will not be read or dropped again (REQ-I3)
+        Some(unsafe { self.inline[self.inline_len].assume_init_read() })
+    }
+
+    /// Returns an immutable reference to the top value without removing it
+    pub fn peek(&self) -> Option<&T> {
+        match self.overflow.last() {
+            Some(x) => Some(x),
+            None => self.inline_slice().last(),
+        }
+    }
+
+    /// Returns a mutable reference to the top value without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if !self.overflow.is_empty() {
+            return self.overflow.last_mut();
+        }
+        self.inline_slice_mut().last_mut()
+    }
+
+    /// Returns the number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.inline_len + self.overflow.len()
+    }
+
+    /// Returns true if the stack contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.inline_len == 0 && self.overflow.is_empty()
+    }
+
+    /// Returns the number of elements stored without heap allocation
+    pub const fn inline_capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns true if some elements currently live on the heap
+    pub fn spilled(&self) -> bool {
+        !self.overflow.is_empty()
+    }
+
+    /// Returns an iterator over the elements from top to bottom
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::InlineStack;
+    /// let mut stack: InlineStack<i32, 2> = InlineStack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// stack.push(3); // spilled
+    /// assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.overflow.iter().rev().chain(self.inline_slice().iter().rev())
+    }
+
+    /// Removes every element, dropping them, but keeps any heap allocation
+    pub fn clear(&mut self) {
+        self.overflow.clear();
+        let len = self.inline_len;
+        self.inline_len = 0; // Set first so a panicking Drop cannot cause a double drop
+        for slot in &mut self.inline[..len] {
+            // SAFETY: slots below the old length were initialized
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+
+    fn inline_slice(&self) -> &[T] {
+        // SAFETY: the first `inline_len` slots are initialized, and
+        // MaybeUninit<T> has the same layout as T
+        unsafe { std::slice::from_raw_parts(self.inline.as_ptr().cast::<T>(), self.inline_len) }
+    }
+
+    fn inline_slice_mut(&mut self) -> &mut [T] {
+        // SAFETY: as in `inline_slice`
+        unsafe { std::slice::from_raw_parts_mut(self.inline.as_mut_ptr().cast::<T>(), self.inline_len) }
+    }
+}
+
+impl<T, const N: usize> Drop for InlineStack<T, N> {
+    /// Drops the initialized inline elements; the overflow Vec drops itself (REQ-I3)
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for InlineStack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for InlineStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InlineStack")
+            .field("inline", &self.inline_slice())
+            .field("overflow", &self.overflow)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test] // REQ-I1, REQ-I2
+    fn spills_only_past_inline_capacity() {
+        let mut s: InlineStack<usize, 3> = InlineStack::new();
+        for i in 0..3 {
+            s.push(i);
+            assert!(!s.spilled());
+        }
+        assert_eq!(s.overflow.capacity(), 0); // No heap allocation yet
+
+        s.push(3);
+        s.push(4);
+        assert!(s.spilled());
+        assert_eq!(s.len(), 5);
+        for expected in (0..5).rev() {
+            assert_eq!(s.peek(), Some(&expected));
+            assert_eq!(s.pop(), Some(expected));
+        }
+        assert_eq!(s.pop(), None);
+    }
+
+    #[test] // REQ-I2: behaves like Vec across the inline/heap boundary
+    fn matches_vec_reference() {
+        let mut s: InlineStack<u32, 4> = InlineStack::new();
+        let mut v = Vec::new();
+        for i in 0..2000u32 {
+            if i.wrapping_mul(2654435761) % 5 < 3 {
+                s.push(i);
+                v.push(i);
+            } else {
+                assert_eq!(s.pop(), v.pop());
+            }
+            if let Some(top) = s.peek_mut() {
+                *top += 1;
+                *v.last_mut().unwrap() += 1;
+            }
+            assert_eq!(s.len(), v.len());
+            assert_eq!(s.peek(), v.last());
+        }
+        assert!(s.iter().eq(v.iter().rev()));
+    }
+
+    #[test] // REQ-I3
+    fn every_element_dropped_exactly_once() {
+        let marker = Rc::new(());
+        {
+            let mut s: InlineStack<Rc<()>, 2> = InlineStack::new();
+            for _ in 0..5 {
+                s.push(Rc::clone(&marker));
+            }
+            drop(s.pop());
+            assert_eq!(Rc::strong_count(&marker), 5);
+        }
+        assert_eq!(Rc::strong_count(&marker), 1);
+    }
+
+    #[test]
+    fn zero_inline_capacity_always_spills() {
+        let mut s: InlineStack<&str, 0> = InlineStack::new();
+        s.push("heap");
+        assert!(s.spilled());
+        assert!(!s.is_empty());
+        assert_eq!(s.pop(), Some("heap"));
+        assert!(s.is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+// A Stack wrapper that records usage and allocation metrics
+//
+// Timing from the outside (examples/performance_check.rs) cannot tell whether
+// a workload reallocated or how deep it went. InstrumentedStack wraps a plain
+// Stack and counts what happens inside, so `with_capacity` hints can be tuned
+// from real data. It is opt-in: Stack itself carries no counters.
+//
+// REQ-M1: Every push and pop shall be counted
+// REQ-M2: The peak length (high-water mark) shall be tracked
+// REQ-M3: Each growth of the backing buffer and the bytes reserved shall be recorded
+// REQ-M4: Metrics shall be opt-in; a plain Stack pays nothing for them
+
+use crate::Stack;
+use std::mem;
+
+/// Usage and allocation metrics collected by an [`InstrumentedStack`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StackStats {
+    /// Number of successful pushes (REQ-M1)
+    pub pushes: u64,
+    /// Number of pops that returned a value (REQ-M1)
+    pub pops: u64,
+    /// Largest length the stack ever reached (REQ-M2)
+    pub peak_len: usize,
+    /// How many times a push had to allocate or reallocate the buffer (REQ-M3)
+    pub grows: u64,
+    /// Bytes currently reserved for elements (`capacity * size_of::<T>()`)
+    pub bytes_reserved: usize,
+    /// Sum of the buffer sizes allocated initially and by every growth, in bytes (REQ-M3)
+    pub total_bytes_reserved: usize,
+}
+
+/// A [`Stack`] that keeps [`StackStats`] about how it is used
+///
+/// # Examples
+/// ```
+/// use mission1::InstrumentedStack;
+///
+/// let mut stack = InstrumentedStack::new();
+/// for depth in [3, 7, 2] {
+///     for i in 0..depth {
+///         stack.push(i);
+///     }
+///     while stack.pop().is_some() {}
+/// }
+///
+/// let stats = stack.stats();
+/// assert_eq!(stats.pushes, 12);
+/// assert_eq!(stats.pops, 12);
+/// assert_eq!(stats.peak_len, 7); // A good `with_capacity` hint for this workload
+/// assert!(stats.grows >= 1);
+///
+/// // With the hint, the same workload never reallocates
+/// let mut tuned = InstrumentedStack::with_capacity(stats.peak_len);
+/// for i in 0..7 {
+///     tuned.push(i);
+/// }
+/// assert_eq!(tuned.stats().grows, 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct InstrumentedStack<T> {
+    inner: Stack<T>,
+    stats: StackStats,
+}
+
+impl<T> InstrumentedStack<T> {
+    /// Creates an empty stack with zeroed statistics
+    pub fn new() -> Self {
+        Self::from_stack(Stack::new())
+    }
+
+    /// Creates an empty stack with pre-allocated capacity
+    ///
+    /// The initial allocation is reflected in `bytes_reserved` and
+    /// `total_bytes_reserved`, but is not a growth.
+    pub fn with_capacity(n: usize) -> Self {
+        Self::from_stack(Stack::with_capacity(n))
+    }
+
+    /// Starts instrumenting an existing stack; its contents count towards `peak_len`
+    pub fn from_stack(inner: Stack<T>) -> Self {
+        let bytes = inner.capacity() * mem::size_of::<T>();
+        let stats = StackStats {
+            peak_len: inner.len(),
+            bytes_reserved: bytes,
+            total_bytes_reserved: bytes,
+            ..StackStats::default()
+        };
+        Self { inner, stats }
+    }
+
+    /// Pushes a value, recording the push and any buffer growth (REQ-M1, REQ-M3)
+    pub fn push(&mut self, x: T) {
+        let before = self.inner.capacity();
+        self.inner.push(x);
+        self.stats.pushes += 1;
+        self.stats.peak_len = self.stats.peak_len.max(self.inner.len());
+        self.observe_capacity(before);
+    }
+
+    /// Pops the top value, recording the pop and any buffer shrink (REQ-M1, REQ-M3)
+    pub fn pop(&mut self) -> Option<T> {
+        let before = self.inner.capacity();
+        let x = self.inner.pop()?;
+        self.stats.pops += 1;
+        self.observe_capacity(before);
+        Some(x)
+    }
+
+    /// Returns an immutable reference to the top value without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.peek()
+    }
+
+    /// Returns a mutable reference to the top value without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.inner.peek_mut()
+    }
+
+    /// Returns the number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the stack contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the capacity of the underlying stack
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns the metrics collected so far
+    pub fn stats(&self) -> &StackStats {
+        &self.stats
+    }
+
+    /// Zeroes the counters; `peak_len` restarts from the current length
+    pub fn reset_stats(&mut self) {
+        self.stats = StackStats {
+            peak_len: self.inner.len(),
+            bytes_reserved: self.stats.bytes_reserved,
+            ..StackStats::default()
+        };
+    }
+
+    /// Read-only access to the wrapped stack (e.g. for `iter`)
+    pub fn inner(&self) -> &Stack<T> {
+        &self.inner
+    }
+
+    /// Stops instrumenting and returns the wrapped stack
+    pub fn into_inner(self) -> Stack<T> {
+        self.inner
+    }
+
+    /// Records a buffer change after an operation that started at `before` capacity
+    fn observe_capacity(&mut self, before: usize) {
+        let after = self.inner.capacity();
+        if after != before {
+            let bytes = after * mem::size_of::<T>();
+            self.stats.bytes_reserved = bytes;
+            if after > before {
+                self.stats.grows += 1;
+                self.stats.total_bytes_reserved += bytes;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test] // REQ-M1, REQ-M2
+    fn counts_operations_and_high_water_mark() {
+        let mut s = InstrumentedStack::new();
+        for i in 0..10 {
+            s.push(i);
+        }
+        for _ in 0..4 {
+            s.pop();
+        }
+        s.push(99);
+        assert_eq!(s.pop(), Some(99));
+        // Popping an empty stack is not counted
+        let mut empty: InstrumentedStack<u8> = InstrumentedStack::new();
+        assert_eq!(empty.pop(), None);
+        assert_eq!(empty.stats().pops, 0);
+
+        let stats = *s.stats();
+        assert_eq!((stats.pushes, stats.pops, stats.peak_len), (11, 5, 10));
+
+        s.reset_stats();
+        assert_eq!(s.stats().pushes, 0);
+        assert_eq!(s.stats().peak_len, 6);
+    }
+
+    #[test] // REQ-M3
+    fn records_each_growth_and_reserved_bytes() {
+        let mut s: InstrumentedStack<u64> = InstrumentedStack::new();
+        let mut expected_grows = 0;
+        let mut expected_total = 0;
+        for i in 0..1000 {
+            let before = s.capacity();
+            s.push(i);
+            if s.capacity() != before {
+                expected_grows += 1;
+                expected_total += s.capacity() * 8;
+            }
+        }
+        let stats = s.stats();
+        assert_eq!(stats.grows, expected_grows);
+        assert!(stats.grows < 20, "doubling growth should need only a few reallocations");
+        assert_eq!(stats.bytes_reserved, s.capacity() * 8);
+        assert_eq!(stats.total_bytes_reserved, expected_total);
+    }
+
+    #[test] // REQ-M3
+    fn capacity_hint_avoids_growth() {
+        let mut s: InstrumentedStack<(char, usize)> = InstrumentedStack::with_capacity(64);
+        for i in 0..64 {
+            s.push(('(', i));
+        }
+        assert_eq!(s.stats().grows, 0);
+        let initial = s.capacity() * mem::size_of::<(char, usize)>();
+        assert_eq!(s.stats().bytes_reserved, initial);
+        assert_eq!(s.stats().total_bytes_reserved, initial); // The hint counts as reserved
+        s.push((')', 64));
+        assert_eq!(s.stats().grows, 1);
+        assert_eq!(s.into_inner().len(), 65);
+    }
+
+    #[test] // REQ-M3
+    fn pop_records_released_capacity() {
+        let mut inner: Stack<u64> = (0..1024).collect();
+        inner.set_shrink_policy(crate::ShrinkPolicy::Quarter);
+        let mut s = InstrumentedStack::from_stack(inner);
+        let total = s.stats().total_bytes_reserved;
+        while s.len() > 8 {
+            s.pop();
+        }
+        assert!(s.capacity() < 1024);
+        assert_eq!(s.stats().bytes_reserved, s.capacity() * 8);
+        // Shrinking is neither a growth nor a new reservation
+        assert_eq!((s.stats().grows, s.stats().total_bytes_reserved), (0, total));
+    }
+}
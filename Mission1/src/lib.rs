@@ -1,10 +1,70 @@
+// `std` is on by default. Without it (`--no-default-features`) the crate is
+// `no_std` and only the allocation-free ArrayStack is available (REQ-F3).
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+pub mod array;
+pub mod lifo;
+#[cfg(feature = "std")]
 pub mod stack;
+#[cfg(feature = "std")]
+pub mod aggregate;
+#[cfg(feature = "std")]
+pub mod bounded;
+#[cfg(feature = "std")]
+pub mod checkpoint;
+#[cfg(feature = "std")]
+pub mod concurrent;
+#[cfg(feature = "std")]
+pub mod conformance;
+#[cfg(feature = "std")]
+pub mod expr;
+#[cfg(feature = "std")]
+pub mod frames;
+#[cfg(feature = "std")]
+pub mod inline;
+#[cfg(feature = "std")]
+pub mod instrumented;
+#[cfg(feature = "std")]
+pub mod persistent;
+#[cfg(feature = "std")]
+pub mod recording;
+#[cfg(feature = "std")]
+pub mod segmented;
+#[cfg(feature = "std")]
+pub mod spill;
+#[cfg(feature = "std")]
+pub mod undo;
+#[cfg(feature = "std")]
 mod experiments;
+#[cfg(feature = "std")]
 mod integration_tests;
 
-pub use stack::Stack;
+pub use array::ArrayStack;
+pub use lifo::Lifo;
+#[cfg(feature = "std")]
+pub use stack::{ShrinkPolicy, Stack};
+#[cfg(feature = "std")]
+pub use aggregate::AggregateStack;
+#[cfg(feature = "std")]
+pub use bounded::{BoundedStack, OverflowPolicy};
+#[cfg(feature = "std")]
+pub use checkpoint::Checkpoint;
+#[cfg(feature = "std")]
+pub use concurrent::ConcurrentStack;
+#[cfg(feature = "std")]
+pub use inline::InlineStack;
+#[cfg(feature = "std")]
+pub use instrumented::{InstrumentedStack, StackStats};
+#[cfg(feature = "std")]
+pub use persistent::PersistentStack;
+#[cfg(feature = "std")]
+pub use recording::RecordingStack;
+#[cfg(feature = "std")]
+pub use segmented::SegmentedStack;
+#[cfg(feature = "std")]
+pub use spill::SpillStack;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -0,0 +1,333 @@
+// The common interface shared by the crate's LIFO stacks
+//
+// Algorithms such as bracket validation only need push / pop / peek / bottom.
+// Writing them against `Lifo<T>` instead of a concrete `Stack<T>` lets callers
+// plug in a bounded, inline, array-backed or instrumented stack without forking
+// the algorithm. The trait only uses `core`, so it is available in `no_std` builds.
+//
+// REQ-8: Every owned, single-threaded stack variant shall implement Lifo<T>
+//        with the same LIFO semantics as Stack<T>
+
+/// A last-in, first-out container with owned elements
+///
+/// Pushing is fallible so fixed-capacity stacks can apply backpressure; stacks
+/// that grow on demand always return `Ok(())`.
+///
+/// [`PersistentStack`](crate::PersistentStack) (whose push returns a new
+/// version) and [`ConcurrentStack`](crate::ConcurrentStack) (which cannot hand
+/// out a borrowed top) deliberately do not implement this trait, nor does
+/// [`SpillStack`](crate::SpillStack), whose pop can fail with an I/O error.
+///
+/// # Examples
+/// ```
+/// use mission1::{ArrayStack, Lifo};
+///
+/// /// Reverses `items`, or reports how many fit before the stack was full
+/// fn reverse_with<S: Lifo<u8>>(items: &[u8], stack: &mut S) -> Result<Vec<u8>, usize> {
+///     for &x in items {
+///         stack.try_push(x).map_err(|_| stack.len())?;
+///     }
+///     let mut out = Vec::new();
+///     while let Some(x) = stack.pop() {
+///         out.push(x);
+///     }
+///     Ok(out)
+/// }
+///
+/// assert_eq!(reverse_with(&[1, 2, 3], &mut ArrayStack::<u8, 8>::new()), Ok(vec![3, 2, 1]));
+/// assert_eq!(reverse_with(&[1, 2, 3], &mut ArrayStack::<u8, 2>::new()), Err(2));
+/// ```
+pub trait Lifo<T> {
+    /// Pushes `x` onto the top of the stack
+    ///
+    /// # Returns
+    /// * `Ok(())` - `x` is now the top element
+    /// * `Err(x)` - The stack refused the value; ownership is handed back
+    fn try_push(&mut self, x: T) -> Result<(), T>;
+
+    /// Removes and returns the top element, transferring ownership
+    fn pop(&mut self) -> Option<T>;
+
+    /// Returns a reference to the top element without removing it
+    fn peek(&self) -> Option<&T>;
+
+    /// Returns a reference to the bottom (oldest) element without removing anything
+    fn bottom(&self) -> Option<&T>;
+
+    /// Returns the number of elements in the stack
+    fn len(&self) -> usize;
+
+    /// Returns true if the stack contains no elements
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T, const N: usize> Lifo<T> for crate::ArrayStack<T, N> {
+    fn try_push(&mut self, x: T) -> Result<(), T> {
+        crate::ArrayStack::try_push(self, x)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        crate::ArrayStack::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        crate::ArrayStack::peek(self)
+    }
+
+    fn bottom(&self) -> Option<&T> {
+        self.iter().next_back()
+    }
+
+    fn len(&self) -> usize {
+        crate::ArrayStack::len(self)
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::Lifo;
+    use crate::{AggregateStack, BoundedStack, InlineStack, InstrumentedStack, RecordingStack, SegmentedStack, Stack};
+
+    impl<T> Lifo<T> for Stack<T> {
+        fn try_push(&mut self, x: T) -> Result<(), T> {
+            self.push(x);
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            Stack::pop(self)
+        }
+
+        fn peek(&self) -> Option<&T> {
+            Stack::peek(self)
+        }
+
+        fn bottom(&self) -> Option<&T> {
+            self.iter().next_back()
+        }
+
+        fn len(&self) -> usize {
+            Stack::len(self)
+        }
+    }
+
+    /// Rejects pushes onto a full stack whatever its
+    /// [`OverflowPolicy`](crate::OverflowPolicy), so `Ok` never means a value was lost
+    impl<T> Lifo<T> for BoundedStack<T> {
+        fn try_push(&mut self, x: T) -> Result<(), T> {
+            if self.is_full() {
+                return Err(x);
+            }
+            BoundedStack::try_push(self, x)
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            BoundedStack::pop(self)
+        }
+
+        fn peek(&self) -> Option<&T> {
+            BoundedStack::peek(self)
+        }
+
+        fn bottom(&self) -> Option<&T> {
+            self.iter().next_back()
+        }
+
+        fn len(&self) -> usize {
+            BoundedStack::len(self)
+        }
+    }
+
+    impl<T, const N: usize> Lifo<T> for InlineStack<T, N> {
+        fn try_push(&mut self, x: T) -> Result<(), T> {
+            self.push(x);
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            InlineStack::pop(self)
+        }
+
+        fn peek(&self) -> Option<&T> {
+            InlineStack::peek(self)
+        }
+
+        fn bottom(&self) -> Option<&T> {
+            self.iter().next_back()
+        }
+
+        fn len(&self) -> usize {
+            InlineStack::len(self)
+        }
+    }
+
+    impl<T> Lifo<T> for InstrumentedStack<T> {
+        fn try_push(&mut self, x: T) -> Result<(), T> {
+            self.push(x);
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            InstrumentedStack::pop(self)
+        }
+
+        fn peek(&self) -> Option<&T> {
+            InstrumentedStack::peek(self)
+        }
+
+        fn bottom(&self) -> Option<&T> {
+            self.inner().iter().next_back()
+        }
+
+        fn len(&self) -> usize {
+            InstrumentedStack::len(self)
+        }
+    }
+
+    /// Only for `T: Unpin`, since `pop` moves values out of their chunk
+    impl<T: Unpin> Lifo<T> for SegmentedStack<T> {
+        fn try_push(&mut self, x: T) -> Result<(), T> {
+            self.push(x);
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            SegmentedStack::pop(self)
+        }
+
+        fn peek(&self) -> Option<&T> {
+            SegmentedStack::peek(self)
+        }
+
+        fn bottom(&self) -> Option<&T> {
+            self.iter().next_back()
+        }
+
+        fn len(&self) -> usize {
+            SegmentedStack::len(self)
+        }
+    }
+
+    impl<T: Clone> Lifo<T> for RecordingStack<T> {
+        fn try_push(&mut self, x: T) -> Result<(), T> {
+            self.push(x);
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            RecordingStack::pop(self)
+        }
+
+        fn peek(&self) -> Option<&T> {
+            RecordingStack::peek(self)
+        }
+
+        fn bottom(&self) -> Option<&T> {
+            self.inner().iter().next_back()
+        }
+
+        fn len(&self) -> usize {
+            RecordingStack::len(self)
+        }
+    }
+
+    impl<T: Clone, F: Fn(&T, &T) -> T> Lifo<T> for AggregateStack<T, F> {
+        fn try_push(&mut self, x: T) -> Result<(), T> {
+            self.push(x);
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            AggregateStack::pop(self)
+        }
+
+        fn peek(&self) -> Option<&T> {
+            AggregateStack::peek(self)
+        }
+
+        fn bottom(&self) -> Option<&T> {
+            self.iter().next_back()
+        }
+
+        fn len(&self) -> usize {
+            AggregateStack::len(self)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{
+        AggregateStack, ArrayStack, BoundedStack, InlineStack, InstrumentedStack, OverflowPolicy,
+        RecordingStack, SegmentedStack, Stack,
+    };
+
+    /// Drives any backend through the same script and returns what it popped
+    fn exercise<S: Lifo<u32>>(stack: &mut S) -> Vec<u32> {
+        let mut popped = Vec::new();
+        for i in 0..6 {
+            if stack.try_push(i).is_err() {
+                popped.push(u32::MAX); // Marks a rejected push
+            }
+            if i % 3 == 2 {
+                popped.extend(stack.pop());
+            }
+        }
+        assert_eq!(stack.peek().is_some(), !stack.is_empty());
+        while let Some(x) = stack.pop() {
+            popped.push(x);
+        }
+        assert!(stack.is_empty());
+        popped
+    }
+
+    #[test] // REQ-8
+    fn growable_backends_agree_with_stack() {
+        let expected = exercise(&mut Stack::new());
+        assert_eq!(expected, vec![2, 5, 4, 3, 1, 0]);
+        assert_eq!(exercise(&mut InlineStack::<u32, 2>::new()), expected);
+        assert_eq!(exercise(&mut AggregateStack::sum()), expected);
+        assert_eq!(exercise(&mut InstrumentedStack::new()), expected);
+        assert_eq!(exercise(&mut SegmentedStack::new()), expected);
+        assert_eq!(exercise(&mut RecordingStack::new()), expected);
+        assert_eq!(exercise(&mut ArrayStack::<u32, 8>::new()), expected);
+        assert_eq!(exercise(&mut BoundedStack::with_capacity(8)), expected);
+    }
+
+    #[test] // REQ-8: full fixed-capacity stacks hand the value back
+    fn bounded_backends_reject_through_the_trait() {
+        let rejected = vec![2, u32::MAX, u32::MAX, 3, 1, 0];
+        assert_eq!(exercise(&mut ArrayStack::<u32, 3>::new()), rejected);
+        assert_eq!(exercise(&mut BoundedStack::with_capacity(3)), rejected);
+
+        // A lossy policy must not turn an eviction into a successful push
+        let mut dropping = BoundedStack::with_policy(2, OverflowPolicy::DropBottom);
+        assert_eq!(exercise(&mut dropping), vec![u32::MAX, 1, u32::MAX, u32::MAX, 3, 0]);
+    }
+
+    /// Pushes 0..4 and checks `bottom` reports the oldest value without consuming it
+    fn oldest<S: Lifo<u32>>(stack: &mut S) -> Option<u32> {
+        assert_eq!(stack.bottom(), None);
+        for i in 0..4 {
+            stack.try_push(i).unwrap();
+        }
+        let bottom = stack.bottom().copied();
+        assert_eq!((stack.len(), stack.peek()), (4, Some(&3)));
+        bottom
+    }
+
+    #[test] // REQ-8
+    fn bottom_reads_the_oldest_element_in_place() {
+        assert_eq!(oldest(&mut Stack::new()), Some(0));
+        assert_eq!(oldest(&mut ArrayStack::<u32, 4>::new()), Some(0));
+        assert_eq!(oldest(&mut BoundedStack::with_capacity(4)), Some(0));
+        assert_eq!(oldest(&mut InlineStack::<u32, 2>::new()), Some(0));
+        assert_eq!(oldest(&mut InstrumentedStack::new()), Some(0));
+        assert_eq!(oldest(&mut SegmentedStack::new()), Some(0));
+        assert_eq!(oldest(&mut RecordingStack::new()), Some(0));
+        assert_eq!(oldest(&mut AggregateStack::sum()), Some(0));
+    }
+}
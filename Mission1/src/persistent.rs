@@ -0,0 +1,287 @@
+// A persistent (immutable, structurally shared) stack
+//
+// Every push/pop returns a NEW version of the stack and leaves the old one
+// untouched. Versions share their common tail through reference counting, so
+// cloning or snapshotting a stack is O(1) no matter how deep it is. This is the
+// classic cons-list used for exploring many search branches without copying.
+//
+// REQ-P1: push and pop shall return a new version in O(1) without modifying the original
+// REQ-P2: clone shall be O(1); versions share tails instead of copying elements
+// REQ-P3: dropping a very long stack shall not overflow the thread stack
+
+use std::rc::Rc;
+
+/// A single cell in the shared chain
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    next: Option<Rc<Node<T>>>,
+}
+
+/// An immutable LIFO stack whose versions share structure
+///
+/// # Type Parameters
+/// * `T` - The type of elements stored in the stack
+///
+/// # Ownership
+/// Elements are owned by reference-counted nodes. A node is freed when the last
+/// version that can reach it is dropped, so popping never moves a value out -
+/// use [`peek`](PersistentStack::peek) to borrow it and `clone` if you need a copy.
+///
+/// # Examples
+/// ```
+/// use mission1::PersistentStack;
+///
+/// let base = PersistentStack::new().push(1).push(2);
+/// let left = base.push(3);   // [3, 2, 1]
+/// let right = base.push(4);  // [4, 2, 1] - shares [2, 1] with `left`
+///
+/// assert_eq!(left.peek(), Some(&3));
+/// assert_eq!(right.peek(), Some(&4));
+/// assert_eq!(base.len(), 2); // The original version is unchanged
+/// ```
+#[derive(Debug)]
+pub struct PersistentStack<T> {
+    head: Option<Rc<Node<T>>>,
+    len: usize,
+}
+
+impl<T> PersistentStack<T> {
+    /// Creates an empty stack
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::PersistentStack;
+    /// let stack: PersistentStack<i32> = PersistentStack::new();
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// Returns a new version with `x` on top (REQ-P1: O(1))
+    ///
+    /// # Ownership
+    /// `x` is moved into a new node; `self` is only borrowed and stays valid.
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::PersistentStack;
+    /// let empty = PersistentStack::new();
+    /// let one = empty.push("a");
+    /// assert!(empty.is_empty());
+    /// assert_eq!(one.len(), 1);
+    /// ```
+    pub fn push(&self, x: T) -> Self {
+        Self {
+            head: Some(Rc::new(Node { value: x, next: self.head.clone() })),
+            len: self.len + 1,
+        }
+    }
+
+    /// Returns a new version without the top element, or `None` if empty (REQ-P1)
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::PersistentStack;
+    /// let stack = PersistentStack::new().push(1).push(2);
+    /// let popped = stack.pop().unwrap();
+    /// assert_eq!(popped.peek(), Some(&1));
+    /// assert_eq!(stack.peek(), Some(&2)); // Still there in the old version
+    /// ```
+    pub fn pop(&self) -> Option<Self> {
+        self.head.as_ref().map(|node| Self {
+            head: node.next.clone(),
+            len: self.len - 1,
+        })
+    }
+
+    /// Returns the top element and the version below it in one step
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::PersistentStack;
+    /// let stack = PersistentStack::new().push('x').push('y');
+    /// let (top, rest) = stack.uncons().unwrap();
+    /// assert_eq!(*top, 'y');
+    /// assert_eq!(rest.peek(), Some(&'x'));
+    /// ```
+    pub fn uncons(&self) -> Option<(&T, Self)> {
+        let node = self.head.as_ref()?;
+        Some((&node.value, Self { head: node.next.clone(), len: self.len - 1 }))
+    }
+
+    /// Returns a reference to the top element without creating a new version
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns the number of elements in this version (O(1), cached)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if this version contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns true if both versions share the exact same top node
+    ///
+    /// Useful for cheap "has this branch changed?" checks; two stacks with
+    /// equal contents built independently are NOT considered the same.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns an iterator over the elements from top to bottom
+    ///
+    /// # Examples
+    /// ```
+    /// use mission1::PersistentStack;
+    /// let stack = PersistentStack::new().push(1).push(2).push(3);
+    /// assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref(), remaining: self.len }
+    }
+}
+
+impl<T> Clone for PersistentStack<T> {
+    /// O(1) snapshot: only the reference count of the top node changes (REQ-P2)
+    fn clone(&self) -> Self {
+        Self { head: self.head.clone(), len: self.len }
+    }
+}
+
+impl<T> Default for PersistentStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Eq> Eq for PersistentStack<T> {}
+
+impl<T> Drop for PersistentStack<T> {
+    /// Unlinks uniquely owned nodes one at a time (REQ-P3)
+    ///
+    /// The default drop would recurse once per node and overflow the thread
+    /// stack for long chains. We stop at the first node still shared with
+    /// another version - that version is now responsible for it.
+    fn drop(&mut self) {
+        let mut cur = self.head.take();
+        while let Some(node) = cur {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => cur = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> FromIterator<T> for PersistentStack<T> {
+    /// Pushes every item in iteration order; the last item ends up on top
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Self::new();
+        for x in iter {
+            stack = stack.push(x);
+        }
+        stack
+    }
+}
+
+/// Iterator over a [`PersistentStack`], yielding items from top to bottom
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> std::iter::FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a PersistentStack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test] // REQ-P1
+    fn old_versions_are_unchanged() {
+        let v0 = PersistentStack::new();
+        let v1 = v0.push(String::from("a"));
+        let v2 = v1.push(String::from("b"));
+        let v3 = v2.pop().unwrap();
+
+        assert!(v0.is_empty());
+        assert_eq!(v1.iter().collect::<Vec<_>>(), vec!["a"]);
+        assert_eq!(v2.iter().collect::<Vec<_>>(), vec!["b", "a"]);
+        assert_eq!(v3, v1);
+        assert!(v0.pop().is_none());
+    }
+
+    #[test] // REQ-P2
+    fn branches_share_tails() {
+        let base: PersistentStack<i32> = (0..1000).collect();
+        let snapshot = base.clone();
+        assert!(snapshot.ptr_eq(&base));
+
+        let left = base.push(-1);
+        let right = base.push(-2);
+        assert!(left.pop().unwrap().ptr_eq(&right.pop().unwrap()));
+        // base's top node is referenced by base, snapshot, left and right
+        assert_eq!(Rc::strong_count(base.head.as_ref().unwrap()), 4);
+    }
+
+    #[test] // REQ-P2: values are freed when the last version referencing them goes away
+    fn nodes_freed_when_unreachable() {
+        let value = Rc::new(());
+        let stack = PersistentStack::new().push(Rc::clone(&value));
+        let branch = stack.push(Rc::new(()));
+        drop(stack);
+        assert_eq!(Rc::strong_count(&value), 2); // Still reachable from `branch`
+        drop(branch);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test] // REQ-P3
+    fn dropping_long_stack_does_not_overflow() {
+        let long: PersistentStack<u64> = (0..1_000_000).collect();
+        let shared = long.pop().unwrap();
+        drop(long);
+        assert_eq!(shared.len(), 999_999);
+        drop(shared);
+    }
+}
@@ -0,0 +1,366 @@
+// An operation log for Stack, for stepping through its history
+//
+// When an algorithm built on a stack (e.g. bracket validation) goes wrong,
+// the final state rarely shows why. RecordingStack wraps a Stack and appends
+// every mutation to a log with a sequence number. The log can be exported as
+// text, replayed to rebuild the stack as it was after any step, and two
+// states can be compared with `diff`.
+//
+// REQ-R1: Every push, pop and peek_mut shall be logged with a sequence number
+// REQ-R2: The log shall be exportable as text, one operation per line
+// REQ-R3: Replaying the log shall reconstruct the state after any step
+// REQ-R4: Two states shall be comparable as (common bottom, removed, added)
+
+use crate::Stack;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// One logged mutation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackEvent<T> {
+    /// A value was pushed
+    Push(T),
+    /// The top value was popped; the popped value is kept for inspection
+    Pop(T),
+    /// The top value was modified through `peek_mut`; holds the value left on top
+    Replace(T),
+}
+
+/// A logged mutation and its sequence number (REQ-R1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry<T> {
+    /// Position in the log, starting at 0
+    pub seq: u64,
+    /// What happened
+    pub event: StackEvent<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for LogEntry<T> {
+    /// Tab-separated `seq`, operation and value, e.g. `3\tpush\t'('`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (op, value) = match &self.event {
+            StackEvent::Push(x) => ("push", x),
+            StackEvent::Pop(x) => ("pop", x),
+            StackEvent::Replace(x) => ("replace", x),
+        };
+        write!(f, "{}\t{}\t{:?}", self.seq, op, value)
+    }
+}
+
+/// Rebuilds a stack by applying `log` to `initial` (bottom to top) (REQ-R3)
+///
+/// # Panics
+/// Panics if the log pops or replaces the top of an empty stack, which a log
+/// produced by [`RecordingStack`] never does
+///
+/// # Examples
+/// ```
+/// use mission1::recording::{replay, LogEntry, StackEvent};
+///
+/// let log = vec![
+///     LogEntry { seq: 0, event: StackEvent::Push(3) },
+///     LogEntry { seq: 1, event: StackEvent::Replace(4) },
+/// ];
+/// let stack = replay(&[1, 2], &log);
+/// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&4, &2, &1]);
+/// ```
+pub fn replay<T: Clone>(initial: &[T], log: &[LogEntry<T>]) -> Stack<T> {
+    let mut stack: Stack<T> = initial.iter().cloned().collect();
+    for entry in log {
+        match &entry.event {
+            StackEvent::Push(x) => stack.push(x.clone()),
+            StackEvent::Pop(_) => {
+                stack.pop().unwrap_or_else(|| panic!("log pops an empty stack at seq {}", entry.seq));
+            }
+            StackEvent::Replace(x) => {
+                *stack
+                    .peek_mut()
+                    .unwrap_or_else(|| panic!("log replaces the top of an empty stack at seq {}", entry.seq)) = x.clone();
+            }
+        }
+    }
+    stack
+}
+
+/// The difference between two stack states (REQ-R4)
+///
+/// Stacks change only at the top, so the difference is described as the
+/// length of the bottom they share, followed by what was removed from the
+/// first state and added to reach the second (both listed bottom to top).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackDiff<T> {
+    /// Number of bottom elements equal in both states
+    pub common: usize,
+    /// Elements of the first state above the common part
+    pub removed: Vec<T>,
+    /// Elements of the second state above the common part
+    pub added: Vec<T>,
+}
+
+impl<T> StackDiff<T> {
+    /// Returns true if both states were equal
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for StackDiff<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "keep {}, remove {:?}, add {:?}", self.common, self.removed, self.added)
+    }
+}
+
+/// Compares two stack states (REQ-R4)
+///
+/// # Examples
+/// ```
+/// use mission1::recording::diff;
+/// use mission1::Stack;
+///
+/// let before: Stack<char> = "([{".chars().collect();
+/// let after: Stack<char> = "([<".chars().collect();
+/// let d = diff(&before, &after);
+/// assert_eq!((d.common, d.removed, d.added), (2, vec!['{'], vec!['<']));
+/// ```
+pub fn diff<T: Clone + PartialEq>(a: &Stack<T>, b: &Stack<T>) -> StackDiff<T> {
+    let common = a.iter().rev().zip(b.iter().rev()).take_while(|(x, y)| x == y).count();
+    StackDiff {
+        common,
+        removed: a.iter().rev().skip(common).cloned().collect(),
+        added: b.iter().rev().skip(common).cloned().collect(),
+    }
+}
+
+/// A [`Stack`] that logs every mutation for later replay
+///
+/// # Examples
+/// ```
+/// use mission1::recording::diff;
+/// use mission1::RecordingStack;
+///
+/// let mut stack = RecordingStack::new();
+/// stack.push('(');
+/// stack.push('[');
+/// *stack.peek_mut().unwrap() = '{';
+/// stack.pop();
+///
+/// assert_eq!(stack.export(), "0\tpush\t'('\n1\tpush\t'['\n2\treplace\t'{'\n3\tpop\t'{'\n");
+///
+/// // Step back in time: the state after the first two operations
+/// let earlier = stack.state_at(2);
+/// assert_eq!(earlier.peek(), Some(&'['));
+/// assert_eq!(diff(&earlier, stack.inner()).removed, vec!['[']);
+/// ```
+#[derive(Debug)]
+pub struct RecordingStack<T: Clone> {
+    inner: Stack<T>,
+    /// Contents (bottom to top) when recording started
+    initial: Vec<T>,
+    log: Vec<LogEntry<T>>,
+}
+
+impl<T: Clone> RecordingStack<T> {
+    /// Creates an empty stack with an empty log
+    pub fn new() -> Self {
+        Self::from_stack(Stack::new())
+    }
+
+    /// Starts recording an existing stack; its contents become the replay starting point
+    pub fn from_stack(inner: Stack<T>) -> Self {
+        let initial = inner.iter().rev().cloned().collect();
+        Self { inner, initial, log: Vec::new() }
+    }
+
+    /// Pushes a value and logs it (REQ-R1)
+    pub fn push(&mut self, x: T) {
+        self.record(StackEvent::Push(x.clone()));
+        self.inner.push(x);
+    }
+
+    /// Pops the top value and logs it; popping an empty stack changes nothing and is not logged (REQ-R1)
+    pub fn pop(&mut self) -> Option<T> {
+        let x = self.inner.pop()?;
+        self.record(StackEvent::Pop(x.clone()));
+        Some(x)
+    }
+
+    /// Returns a reference to the top value; reads are not logged
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.peek()
+    }
+
+    /// Returns a guard for modifying the top value (REQ-R1)
+    ///
+    /// When the guard is dropped the value left on top is logged as
+    /// [`StackEvent::Replace`], whether or not it was actually changed.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        if self.inner.is_empty() {
+            None
+        } else {
+            Some(PeekMut { stack: self })
+        }
+    }
+
+    /// Returns the number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the stack contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Read-only access to the current state
+    pub fn inner(&self) -> &Stack<T> {
+        &self.inner
+    }
+
+    /// The contents (bottom to top) when recording started
+    pub fn initial(&self) -> &[T] {
+        &self.initial
+    }
+
+    /// The mutations logged so far, oldest first
+    pub fn log(&self) -> &[LogEntry<T>] {
+        &self.log
+    }
+
+    /// Rebuilds the state after the first `step` logged operations (REQ-R3)
+    ///
+    /// `state_at(0)` is the initial state and `state_at(log().len())` equals
+    /// the current one.
+    ///
+    /// # Panics
+    /// Panics if `step` exceeds the log length
+    pub fn state_at(&self, step: usize) -> Stack<T> {
+        assert!(step <= self.log.len(), "step {} exceeds log length {}", step, self.log.len());
+        replay(&self.initial, &self.log[..step])
+    }
+
+    /// Stops recording and returns the stack together with its log
+    pub fn into_parts(self) -> (Stack<T>, Vec<LogEntry<T>>) {
+        (self.inner, self.log)
+    }
+
+    fn record(&mut self, event: StackEvent<T>) {
+        let seq = self.log.len() as u64;
+        self.log.push(LogEntry { seq, event });
+    }
+}
+
+impl<T: Clone + fmt::Debug> RecordingStack<T> {
+    /// Exports the log as text, one tab-separated `seq op value` line per entry (REQ-R2)
+    pub fn export(&self) -> String {
+        self.log.iter().map(|entry| format!("{}\n", entry)).collect()
+    }
+}
+
+impl<T: Clone> Default for RecordingStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Mutable access to the top of a [`RecordingStack`]; logs the final value on drop
+///
+/// Created by [`RecordingStack::peek_mut`].
+#[derive(Debug)]
+pub struct PeekMut<'a, T: Clone> {
+    stack: &'a mut RecordingStack<T>,
+}
+
+impl<T: Clone> Deref for PeekMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.stack.inner.peek().expect("PeekMut exists only for a non-empty stack")
+    }
+}
+
+impl<T: Clone> DerefMut for PeekMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.stack.inner.peek_mut().expect("PeekMut exists only for a non-empty stack")
+    }
+}
+
+impl<T: Clone> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        let top = (**self).clone();
+        self.stack.record(StackEvent::Replace(top));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test] // REQ-R1
+    fn logs_mutations_with_sequence_numbers() {
+        let mut s = RecordingStack::new();
+        s.push(1);
+        s.push(2);
+        if let Some(mut top) = s.peek_mut() {
+            *top *= 10;
+        }
+        assert_eq!(s.pop(), Some(20));
+        assert_eq!(s.pop(), Some(1));
+        assert_eq!(s.pop(), None); // Not a mutation, not logged
+        assert_eq!(s.peek(), None);
+        assert!(s.peek_mut().is_none());
+
+        let events: Vec<(u64, StackEvent<i32>)> = s.log().iter().map(|e| (e.seq, e.event.clone())).collect();
+        assert_eq!(
+            events,
+            vec![
+                (0, StackEvent::Push(1)),
+                (1, StackEvent::Push(2)),
+                (2, StackEvent::Replace(20)),
+                (3, StackEvent::Pop(20)),
+                (4, StackEvent::Pop(1)),
+            ]
+        );
+    }
+
+    #[test] // REQ-R2
+    fn exports_one_line_per_operation() {
+        let mut s = RecordingStack::from_stack(Stack::from_iter(["base".to_string()]));
+        s.push("a\tb".to_string());
+        s.pop();
+        let text = s.export();
+        assert_eq!(text.lines().collect::<Vec<_>>(), vec!["0\tpush\t\"a\\tb\"", "1\tpop\t\"a\\tb\""]);
+    }
+
+    #[test] // REQ-R3
+    fn replay_reconstructs_every_step() {
+        let initial: Stack<u32> = (0..3).collect();
+        let mut s = RecordingStack::from_stack(initial);
+        let mut snapshots: Vec<Vec<u32>> = vec![s.inner().iter().copied().collect()];
+        for i in 0..40u32 {
+            match i % 5 {
+                0 | 1 | 3 => s.push(i),
+                2 => *s.peek_mut().unwrap() += 100,
+                _ => {
+                    s.pop();
+                }
+            }
+            snapshots.push(s.inner().iter().copied().collect());
+        }
+        for (step, expected) in snapshots.iter().enumerate() {
+            assert_eq!(&s.state_at(step).iter().copied().collect::<Vec<_>>(), expected, "step {}", step);
+        }
+        let (stack, log) = s.into_parts();
+        assert_eq!(replay(&[0, 1, 2], &log).iter().collect::<Vec<_>>(), stack.iter().collect::<Vec<_>>());
+    }
+
+    #[test] // REQ-R4
+    fn diff_reports_common_bottom() {
+        let a: Stack<i32> = vec![1, 2, 3, 4].into_iter().collect();
+        let b: Stack<i32> = vec![1, 2, 9].into_iter().collect();
+        let d = diff(&a, &b);
+        assert_eq!(d, StackDiff { common: 2, removed: vec![3, 4], added: vec![9] });
+        assert_eq!(d.to_string(), "keep 2, remove [3, 4], add [9]");
+        assert!(diff(&a, &a).is_empty());
+        assert_eq!(diff(&Stack::new(), &b).added, vec![1, 2, 9]);
+    }
+}
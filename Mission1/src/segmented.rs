@@ -0,0 +1,281 @@
+// A stack whose elements never move once pushed
+//
+// Stack<T> grows its Vec by reallocating, which moves every element and
+// causes an O(n) copy spike. SegmentedStack stores elements in a list of
+// fixed-capacity chunks whose sizes double (8, 16, 32, ...). A full chunk is
+// never grown: the next push starts a new chunk, so existing elements keep
+// their address until they are removed. That makes pinned access possible.
+//
+// REQ-S1: An element's address shall not change from push until it is removed
+// REQ-S2: push shall be O(1) in the worst case and never copy existing elements
+// REQ-S3: Pinned access shall be offered; moving a value out (pop, peek_mut)
+//         shall require T: Unpin, while drop_top removes !Unpin values in place
+// REQ-S4: Emptied chunks shall be released, keeping at most one spare chunk
+
+use std::fmt;
+use std::pin::Pin;
+
+/// Capacity of the first chunk; chunk `k` holds `FIRST_CHUNK << k` elements
+const FIRST_CHUNK: usize = 8;
+
+/// Upper bound on the number of chunks: their capacities sum past `usize::MAX` beyond this
+const MAX_CHUNKS: usize = (usize::BITS - FIRST_CHUNK.trailing_zeros()) as usize;
+
+/// A LIFO stack with stable element addresses and no reallocation pauses
+///
+/// # Memory Layout
+/// ```text
+/// chunks[0]: [e0 .. e7]            capacity 8   (full)
+/// chunks[1]: [e8 .. e23]           capacity 16  (full)
+/// chunks[2]: [e24, e25, _, ...]    capacity 32  <- top chunk
+/// ```
+/// The outer list reserves room for every chunk it could ever need when the
+/// first chunk is allocated, so it never reallocates either.
+///
+/// # Examples
+/// ```
+/// use mission1::SegmentedStack;
+///
+/// let mut stack = SegmentedStack::new();
+/// stack.push(0u64);
+/// let first = stack.peek().unwrap() as *const u64;
+///
+/// for i in 1..10_000 {
+///     stack.push(i);
+/// }
+/// // The bottom element never moved, so the raw pointer is still valid
+/// assert_eq!(stack.iter().last().map(|x| x as *const u64), Some(first));
+/// assert_eq!(stack.pop(), Some(9_999));
+/// ```
+pub struct SegmentedStack<T> {
+    /// Each chunk's capacity is fixed at allocation; chunks below `top` are full
+    chunks: Vec<Vec<T>>,
+    /// Index of the chunk holding the top element (0 when empty)
+    top: usize,
+    len: usize,
+}
+
+impl<T> SegmentedStack<T> {
+    /// Creates an empty stack; nothing is allocated until the first push
+    pub const fn new() -> Self {
+        Self { chunks: Vec::new(), top: 0, len: 0 }
+    }
+
+    /// Pushes a value without moving any existing element (REQ-S1, REQ-S2)
+    ///
+    /// Worst case O(1): when the top chunk is full a new, empty chunk is
+    /// allocated (or the spare one reused) instead of copying anything.
+    pub fn push(&mut self, x: T) {
+        if self.chunks.is_empty() {
+            // The only allocation of the outer list
+            self.chunks.reserve_exact(MAX_CHUNKS);
+            self.chunks.push(Vec::with_capacity(FIRST_CHUNK));
+        } else if self.chunks[self.top].len() == self.chunks[self.top].capacity() {
+            self.top += 1;
+            if self.top == self.chunks.len() {
+                self.chunks.push(Vec::with_capacity(FIRST_CHUNK << self.top));
+            }
+        }
+        // Never exceeds the chunk's capacity, so this push cannot reallocate
+        self.chunks[self.top].push(x);
+        self.len += 1;
+    }
+
+    /// Removes the top value, dropping it in place (REQ-S3)
+    ///
+    /// Works for any `T`, including `!Unpin` values that may not be moved.
+    ///
+    /// # Returns
+    /// `false` if the stack was empty
+    pub fn drop_top(&mut self) -> bool {
+        let Some(chunk) = self.chunks.get_mut(self.top) else { return false };
+        let Some(new_len) = chunk.len().checked_sub(1) else { return false };
+        chunk.truncate(new_len); // Drops the value where it lives
+        self.after_remove();
+        true
+    }
+
+    /// Returns a reference to the top value
+    pub fn peek(&self) -> Option<&T> {
+        self.chunks.get(self.top)?.last()
+    }
+
+    /// Returns a pinned reference to the top value (REQ-S3)
+    pub fn peek_pin(&self) -> Option<Pin<&T>> {
+        // SAFETY: elements are never moved while stored (REQ-S1); they leave
+        // the stack only by being dropped in place or, for T: Unpin, by pop
+        self.peek().map(|x| unsafe { Pin::new_unchecked(x) })
+    }
+
+    /// Returns a pinned mutable reference to the top value (REQ-S3)
+    ///
+    /// Unlike [`peek_mut`](SegmentedStack::peek_mut) this is available for
+    /// `!Unpin` types, because a `Pin<&mut T>` cannot be used to move the value.
+    pub fn peek_pin_mut(&mut self) -> Option<Pin<&mut T>> {
+        let top = self.chunks.get_mut(self.top)?.last_mut()?;
+        // SAFETY: as in `peek_pin`
+        Some(unsafe { Pin::new_unchecked(top) })
+    }
+
+    /// Returns the number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the stack contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns how many elements fit in the currently allocated chunks
+    pub fn capacity(&self) -> usize {
+        self.chunks.iter().map(Vec::capacity).fold(0, usize::saturating_add)
+    }
+
+    /// Returns the number of allocated chunks, including a spare one
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns an iterator over the elements from top to bottom
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let used = if self.chunks.is_empty() { 0 } else { self.top + 1 };
+        self.chunks[..used].iter().rev().flat_map(|chunk| chunk.iter().rev())
+    }
+
+    /// Steps down to the chunk below once the top chunk runs empty (REQ-S4)
+    fn after_remove(&mut self) {
+        self.len -= 1;
+        if self.chunks[self.top].is_empty() && self.top > 0 {
+            self.top -= 1;
+            // Keep the emptied chunk as a spare so a push/pop sequence at a
+            // chunk boundary does not allocate every time; free anything above it
+            self.chunks.truncate(self.top + 2);
+        }
+    }
+}
+
+impl<T: Unpin> SegmentedStack<T> {
+    /// Pops and returns the top value, transferring ownership (REQ-S3)
+    ///
+    /// Requires `T: Unpin`: moving a value out would break the pinning
+    /// promise of [`peek_pin_mut`](SegmentedStack::peek_pin_mut) otherwise.
+    /// Use [`drop_top`](SegmentedStack::drop_top) for `!Unpin` types.
+    pub fn pop(&mut self) -> Option<T> {
+        let x = self.chunks.get_mut(self.top)?.pop()?;
+        self.after_remove();
+        Some(x)
+    }
+
+    /// Returns a mutable reference to the top value (requires `T: Unpin`)
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.chunks.get_mut(self.top)?.last_mut()
+    }
+}
+
+impl<T> Default for SegmentedStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SegmentedStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SegmentedStack")
+            .field("items", &self.iter().rev().collect::<Vec<_>>())
+            .field("chunks", &self.chunks.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::marker::PhantomPinned;
+    use std::rc::Rc;
+
+    #[test] // REQ-S1, REQ-S2
+    fn elements_never_move() {
+        let mut s = SegmentedStack::new();
+        let mut addresses = Vec::new();
+        for i in 0..5_000u32 {
+            s.push(i);
+            addresses.push(s.peek().unwrap() as *const u32);
+        }
+        let now: Vec<*const u32> = s.iter().rev().map(|x| x as *const u32).collect();
+        assert_eq!(now, addresses);
+        // Doubling chunks: 8 + 16 + ... covers 5_000 elements in 10 chunks
+        assert_eq!(s.chunk_count(), 10);
+    }
+
+    #[test] // REQ-S1
+    fn matches_vec_reference() {
+        let mut s = SegmentedStack::new();
+        let mut v = Vec::new();
+        for i in 0..3_000u32 {
+            if i.wrapping_mul(2654435761) % 7 < 4 {
+                s.push(i);
+                v.push(i);
+            } else {
+                assert_eq!(s.pop(), v.pop());
+            }
+            if let Some(top) = s.peek_mut() {
+                *top += 1;
+                *v.last_mut().unwrap() += 1;
+            }
+            assert_eq!(s.len(), v.len());
+            assert_eq!(s.peek(), v.last());
+        }
+        assert!(s.iter().eq(v.iter().rev()));
+    }
+
+    #[test] // REQ-S3
+    fn pinned_values_are_dropped_in_place() {
+        struct SelfAware {
+            marker: Rc<()>,
+            _pin: PhantomPinned,
+        }
+
+        let marker = Rc::new(());
+        let mut s = SegmentedStack::new();
+        for _ in 0..20 {
+            s.push(SelfAware { marker: Rc::clone(&marker), _pin: PhantomPinned });
+        }
+        let pinned: Pin<&mut SelfAware> = s.peek_pin_mut().unwrap();
+        assert_eq!(Rc::strong_count(&pinned.marker), 21);
+        assert!(s.peek_pin().is_some());
+
+        for _ in 0..5 {
+            assert!(s.drop_top());
+        }
+        assert_eq!(Rc::strong_count(&marker), 16);
+        drop(s);
+        assert_eq!(Rc::strong_count(&marker), 1);
+    }
+
+    #[test] // REQ-S4
+    fn keeps_one_spare_chunk_at_the_boundary() {
+        let mut s = SegmentedStack::new();
+        for i in 0..8 {
+            s.push(i);
+        }
+        assert_eq!(s.chunk_count(), 1);
+        for _ in 0..100 {
+            s.push(8); // Opens chunk 1 (reused after the first time)
+            s.pop();
+            s.pop(); // Chunk 1 is empty again: kept as the spare
+            s.push(7);
+        }
+        assert_eq!(s.chunk_count(), 2);
+
+        for i in 0..1_000 {
+            s.push(i);
+        }
+        while s.drop_top() {}
+        assert!(s.is_empty());
+        assert_eq!(s.chunk_count(), 2);
+        assert_eq!(s.capacity(), 8 + 16);
+        assert!(!s.drop_top());
+        assert_eq!(s.pop(), None);
+    }
+}
@@ -0,0 +1,540 @@
+// A stack that spills its oldest elements to disk
+//
+// A depth-first search over a huge state graph can push far more states than
+// fit in RAM, and Stack<T> then aborts with OOM. Only the top of the stack is
+// touched often, so SpillStack keeps the top `mem_limit` elements in memory
+// and writes older ones, half a window at a time, into segment files in a
+// private temp directory. Segments form a stack of their own: the newest file
+// is read back when the in-memory part runs out.
+//
+// REQ-D1: At most `mem_limit` elements shall be held in memory
+// REQ-D2: Spilled elements shall come back in exact LIFO order
+// REQ-D3: Elements shall be (de)serialized through the Spill trait
+// REQ-D4: The temp directory shall be newly created (never an existing path), and it
+//         and its segment files shall be removed when consumed or dropped
+// REQ-D5: I/O failures shall be returned as io::Error, leaving the stack unchanged
+
+use std::collections::VecDeque;
+use std::collections::hash_map::RandomState;
+use std::fs::{self, File, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Conversion of a value to and from bytes in a segment file (REQ-D3)
+///
+/// Implemented for the primitive types, `String`, `Vec<T>`, `Option<T>` and
+/// tuples of up to three elements. A custom state type usually implements it
+/// by writing its fields in order.
+///
+/// # Examples
+/// ```
+/// use mission1::spill::Spill;
+/// use std::io::{self, Read, Write};
+///
+/// struct State { node: u32, path: Vec<u32> }
+///
+/// impl Spill for State {
+///     fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+///         self.node.write_to(w)?;
+///         self.path.write_to(w)
+///     }
+///
+///     fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+///         Ok(State { node: u32::read_from(r)?, path: Vec::read_from(r)? })
+///     }
+/// }
+/// ```
+pub trait Spill: Sized {
+    /// Writes the value's bytes
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()>;
+
+    /// Reads back a value written by [`write_to`](Spill::write_to)
+    fn read_from(r: &mut dyn Read) -> io::Result<Self>;
+}
+
+macro_rules! spill_le_bytes {
+    ($($t:ty),*) => {$(
+        impl Spill for $t {
+            fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+                w.write_all(&self.to_le_bytes())
+            }
+
+            fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+                let mut bytes = [0; std::mem::size_of::<$t>()];
+                r.read_exact(&mut bytes)?;
+                Ok(<$t>::from_le_bytes(bytes))
+            }
+        }
+    )*};
+}
+
+spill_le_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Sizes are stored as `u64` so files do not depend on the platform's pointer width
+impl Spill for usize {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as u64).write_to(w)
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        usize::try_from(u64::read_from(r)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl Spill for isize {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as i64).write_to(w)
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        isize::try_from(i64::read_from(r)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl Spill for bool {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as u8).write_to(w)
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        Ok(u8::read_from(r)? != 0)
+    }
+}
+
+impl Spill for char {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as u32).write_to(w)
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        char::from_u32(u32::read_from(r)?).ok_or_else(|| invalid("invalid char"))
+    }
+}
+
+impl Spill for String {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.len().write_to(w)?;
+        w.write_all(self.as_bytes())
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        let len = usize::read_from(r)?;
+        let mut bytes = Vec::new();
+        r.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl<T: Spill> Spill for Vec<T> {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.len().write_to(w)?;
+        self.iter().try_for_each(|x| x.write_to(w))
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        let len = usize::read_from(r)?;
+        // No `with_capacity(len)`: a corrupt length must not trigger a huge allocation
+        (0..len).map(|_| T::read_from(r)).collect()
+    }
+}
+
+impl<T: Spill> Spill for Option<T> {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.is_some().write_to(w)?;
+        self.as_ref().map_or(Ok(()), |x| x.write_to(w))
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        if bool::read_from(r)? { T::read_from(r).map(Some) } else { Ok(None) }
+    }
+}
+
+impl<A: Spill, B: Spill> Spill for (A, B) {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.0.write_to(w)?;
+        self.1.write_to(w)
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        Ok((A::read_from(r)?, B::read_from(r)?))
+    }
+}
+
+impl<A: Spill, B: Spill, C: Spill> Spill for (A, B, C) {
+    fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.0.write_to(w)?;
+        self.1.write_to(w)?;
+        self.2.write_to(w)
+    }
+
+    fn read_from(r: &mut dyn Read) -> io::Result<Self> {
+        Ok((A::read_from(r)?, B::read_from(r)?, C::read_from(r)?))
+    }
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Distinguishes the directories of stacks created by the same process
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+/// How many fresh names `create_private_dir` tries before giving up
+const DIR_ATTEMPTS: usize = 64;
+
+/// Creates a new, empty directory under `parent` that did not exist before
+///
+/// The name carries a random suffix, and `create_dir` fails on any existing
+/// path (including a planted symlink), so another user cannot pre-create the
+/// directory and have segments written into it or removed from it. On Unix
+/// it is also made accessible to the owner only.
+fn create_private_dir(parent: &Path) -> io::Result<PathBuf> {
+    for _ in 0..DIR_ATTEMPTS {
+        let mut h = RandomState::new().build_hasher();
+        h.write_usize(NEXT_DIR.fetch_add(1, Ordering::Relaxed));
+        let dir = parent.join(format!("mission1-spill-{}-{:016x}", std::process::id(), h.finish()));
+
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        match builder.create(&dir) {
+            Ok(()) => return Ok(dir),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::new(io::ErrorKind::AlreadyExists, "could not find an unused spill directory name"))
+}
+
+/// A spilled run of elements, stored bottom to top
+#[derive(Debug)]
+struct Segment {
+    path: PathBuf,
+    len: usize,
+}
+
+/// A LIFO stack that keeps only its top elements in memory
+///
+/// # Memory Layout
+/// ```text
+/// disk:   seg-0 [oldest ..]  seg-1 [..]          (read back newest first)
+/// memory: hot   [.. top]                         (at most mem_limit elements)
+/// ```
+/// When a push finds `hot` full, its bottom half is written to a new segment.
+/// When a pop empties `hot`, the newest segment is loaded back right away, so
+/// [`peek`](SpillStack::peek) never touches the disk.
+///
+/// # Examples
+/// ```
+/// use mission1::SpillStack;
+///
+/// let mut stack = SpillStack::new(100);
+/// for i in 0..1_000u64 {
+///     stack.push(i).unwrap();
+/// }
+/// assert!(stack.in_memory() <= 100);
+/// assert!(stack.segment_count() > 0);
+///
+/// for i in (0..1_000u64).rev() {
+///     assert_eq!(stack.pop().unwrap(), Some(i));
+/// }
+/// assert_eq!(stack.pop().unwrap(), None);
+/// ```
+#[derive(Debug)]
+pub struct SpillStack<T> {
+    /// In-memory top of the stack; the front is the oldest element
+    hot: VecDeque<T>,
+    /// Spilled elements, oldest segment first
+    segments: Vec<Segment>,
+    /// Maximum length of `hot` (REQ-D1)
+    mem_limit: usize,
+    /// Where the private directory is created
+    parent: PathBuf,
+    /// The private directory, created on the first spill (REQ-D4)
+    dir: Option<PathBuf>,
+    /// Used to name segment files uniquely
+    next_segment: usize,
+}
+
+impl<T: Spill> SpillStack<T> {
+    /// Creates a stack keeping `mem_limit` elements in memory, spilling under the system temp directory
+    ///
+    /// # Panics
+    /// Panics if `mem_limit` is 0
+    pub fn new(mem_limit: usize) -> Self {
+        Self::with_dir(mem_limit, std::env::temp_dir())
+    }
+
+    /// Like [`new`](SpillStack::new), but spills into a fresh directory inside `parent`
+    ///
+    /// # Panics
+    /// Panics if `mem_limit` is 0
+    pub fn with_dir(mem_limit: usize, parent: impl AsRef<Path>) -> Self {
+        assert!(mem_limit > 0, "mem_limit must be > 0");
+        Self {
+            hot: VecDeque::new(),
+            segments: Vec::new(),
+            mem_limit,
+            parent: parent.as_ref().to_path_buf(),
+            dir: None,
+            next_segment: 0,
+        }
+    }
+
+    /// Pushes a value, first spilling the oldest in-memory half if memory is full (REQ-D1)
+    ///
+    /// # Returns
+    /// `Err` if the spill could not be written; the stack is then unchanged
+    pub fn push(&mut self, x: T) -> io::Result<()> {
+        if self.hot.len() == self.mem_limit {
+            self.spill()?;
+        }
+        self.hot.push_back(x);
+        Ok(())
+    }
+
+    /// Pops the top value, reading the newest segment back in when needed (REQ-D2)
+    ///
+    /// # Returns
+    /// * `Ok(Some(x))` - The top value
+    /// * `Ok(None)` - The stack is empty
+    /// * `Err(e)` - Reloading a segment failed; nothing was removed (REQ-D5)
+    pub fn pop(&mut self) -> io::Result<Option<T>> {
+        let Some(x) = self.hot.pop_back() else { return Ok(None) };
+        // Refill as soon as memory runs empty, so `peek` stays disk-free. Doing
+        // it after the pop keeps the reload itself within `mem_limit` (REQ-D1)
+        if self.hot.is_empty() && !self.segments.is_empty() {
+            if let Err(e) = self.reload() {
+                self.hot.push_back(x);
+                return Err(e);
+            }
+        }
+        Ok(Some(x))
+    }
+
+    /// Returns the top value without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.hot.back()
+    }
+
+    /// Returns the top value mutably without removing it
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.hot.back_mut()
+    }
+
+    /// Returns the total number of elements, in memory and on disk
+    pub fn len(&self) -> usize {
+        self.hot.len() + self.spilled()
+    }
+
+    /// Returns true if the stack contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.hot.is_empty() && self.segments.is_empty()
+    }
+
+    /// Returns the number of elements currently held in memory
+    pub fn in_memory(&self) -> usize {
+        self.hot.len()
+    }
+
+    /// Returns the number of elements currently stored on disk
+    pub fn spilled(&self) -> usize {
+        self.segments.iter().map(|s| s.len).sum()
+    }
+
+    /// Returns the number of segment files on disk
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the configured in-memory limit
+    pub fn mem_limit(&self) -> usize {
+        self.mem_limit
+    }
+
+    /// Returns the private spill directory, once the first spill has created it
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Writes the oldest half of `hot` to a new segment file
+    fn spill(&mut self) -> io::Result<()> {
+        let count = (self.mem_limit / 2).max(1);
+        let dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => {
+                let dir = create_private_dir(&self.parent)?;
+                self.dir.insert(dir).clone()
+            }
+        };
+        let path = dir.join(format!("seg-{}.bin", self.next_segment));
+
+        // create_new: never follow or reuse a file that is already there
+        let written = OpenOptions::new().write(true).create_new(true).open(&path).and_then(|file| {
+            let mut w = BufWriter::new(file);
+            self.hot.iter().take(count).try_for_each(|x| x.write_to(&mut w))?;
+            w.into_inner().map_err(io::IntoInnerError::into_error)?.sync_all()
+        });
+        if let Err(e) = written {
+            let _ = fs::remove_file(&path);
+            return Err(e);
+        }
+
+        self.next_segment += 1;
+        self.hot.drain(..count);
+        self.segments.push(Segment { path, len: count });
+        Ok(())
+    }
+
+    /// Reads the newest segment back below the in-memory elements and deletes its file
+    fn reload(&mut self) -> io::Result<()> {
+        let segment = self.segments.last().expect("caller checked for segments");
+        let mut r = BufReader::new(File::open(&segment.path)?);
+        let loaded = (0..segment.len).map(|_| T::read_from(&mut r)).collect::<io::Result<Vec<T>>>()?;
+
+        let segment = self.segments.pop().expect("checked above");
+        for x in loaded.into_iter().rev() {
+            self.hot.push_front(x);
+        }
+        fs::remove_file(&segment.path)
+            .or_else(|e| if e.kind() == io::ErrorKind::NotFound { Ok(()) } else { Err(e) })
+    }
+}
+
+impl<T> Drop for SpillStack<T> {
+    /// Deletes every remaining segment together with the private directory (REQ-D4)
+    fn drop(&mut self) {
+        if let Some(dir) = &self.dir {
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A parent directory private to one test, so tests can inspect it in parallel
+    fn test_parent(name: &str) -> PathBuf {
+        let parent = std::env::temp_dir().join(format!("mission1-spill-test-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&parent).unwrap();
+        parent
+    }
+
+    #[test] // REQ-D1, REQ-D2
+    fn spills_and_reloads_in_lifo_order() {
+        let mut s = SpillStack::new(4);
+        for i in 0..100u32 {
+            s.push(i).unwrap();
+            assert!(s.in_memory() <= 4);
+        }
+        assert_eq!(s.len(), 100);
+        assert_eq!(s.segment_count(), 48); // 2 elements per segment, 4 kept in memory
+        assert_eq!(s.peek(), Some(&99));
+
+        let popped: Vec<u32> = std::iter::from_fn(|| s.pop().unwrap()).collect();
+        assert_eq!(popped, (0..100).rev().collect::<Vec<_>>());
+        assert_eq!(s.segment_count(), 0);
+        assert!(s.is_empty());
+    }
+
+    #[test] // REQ-D1, REQ-D2
+    fn single_element_limit_is_never_exceeded() {
+        let mut s = SpillStack::new(1);
+        for i in 0..20u16 {
+            s.push(i).unwrap();
+            assert_eq!(s.in_memory(), 1);
+        }
+        for i in (0..20u16).rev() {
+            assert_eq!(s.pop().unwrap(), Some(i));
+            assert!(s.in_memory() <= 1);
+            assert_eq!(s.peek(), i.checked_sub(1).as_ref());
+        }
+        assert!(s.is_empty());
+    }
+
+    #[test] // REQ-D4
+    fn private_dirs_are_fresh_and_distinct() {
+        let parent = test_parent("fresh");
+        let dirs: Vec<PathBuf> = (0..8).map(|_| create_private_dir(&parent).unwrap()).collect();
+        for (i, dir) in dirs.iter().enumerate() {
+            assert!(!dirs[..i].contains(dir));
+            assert_eq!(fs::read_dir(dir).unwrap().count(), 0);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                assert_eq!(fs::metadata(dir).unwrap().permissions().mode() & 0o777, 0o700);
+            }
+        }
+
+        // A stack spills into its own new directory, never one that already exists
+        let mut s = SpillStack::with_dir(1, &parent);
+        s.push(1u8).unwrap();
+        s.push(2u8).unwrap();
+        assert!(!dirs.iter().any(|d| Some(d.as_path()) == s.dir()));
+        drop(s);
+        fs::remove_dir_all(&parent).unwrap();
+    }
+
+    #[test] // REQ-D2, REQ-D3
+    fn interleaved_operations_match_vec() {
+        let mut s = SpillStack::new(5);
+        let mut v: Vec<(String, Vec<i64>, Option<char>)> = Vec::new();
+        for i in 0..2_000i64 {
+            if i.wrapping_mul(2654435761) % 5 < 3 {
+                let item = (format!("state-{}", i), vec![i, -i], char::from_u32(i as u32 % 128));
+                s.push(item.clone()).unwrap();
+                v.push(item);
+            } else {
+                assert_eq!(s.pop().unwrap(), v.pop());
+            }
+            assert_eq!(s.len(), v.len());
+            assert_eq!(s.peek(), v.last());
+        }
+    }
+
+    #[test] // REQ-D4
+    fn removes_files_when_consumed_and_dropped() {
+        let parent = test_parent("drop");
+        let mut s = SpillStack::with_dir(2, &parent);
+        for i in 0..10u8 {
+            s.push(i).unwrap();
+        }
+        let dir = s.dir().unwrap().to_path_buf();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), s.segment_count());
+
+        s.pop().unwrap();
+        s.pop().unwrap();
+        s.pop().unwrap(); // Reloads one segment and deletes its file
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), s.segment_count());
+
+        drop(s);
+        assert!(!dir.exists());
+        assert_eq!(fs::read_dir(&parent).unwrap().count(), 0);
+        fs::remove_dir(&parent).unwrap();
+    }
+
+    #[test] // REQ-D5
+    fn failed_reload_leaves_stack_unchanged() {
+        let parent = test_parent("corrupt");
+        let mut s = SpillStack::with_dir(2, &parent);
+        for i in 0..5u64 {
+            s.push(i).unwrap();
+        }
+        let newest = s.segments.last().unwrap().path.clone();
+        fs::write(&newest, [1, 2, 3]).unwrap(); // Truncated: not even one u64
+
+        assert_eq!(s.pop().unwrap(), Some(4));
+        assert_eq!(s.pop().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.peek(), Some(&3));
+
+        drop(s);
+        fs::remove_dir_all(&parent).unwrap();
+    }
+}
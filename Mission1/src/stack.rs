@@ -6,6 +6,33 @@
 // REQ-3: Pop transfers ownership in O(1) time  
 // REQ-4: No access to values after they've been popped (memory safety)
 // REQ-5: Safe borrowing with peek() and peek_mut()
+// REQ-6: Non-destructive iteration in LIFO (top-to-bottom) order
+// REQ-7: Checkpoint / rollback in O(changes) time
+// REQ-9: Configurable shrinking that keeps push/pop amortized O(1)
+// REQ-10: Bulk operations (pop_n, peek_n, truncate, split_off, append)
+// REQ-11: Optional serde support (feature "serde") as a bottom-to-top sequence
+
+use crate::checkpoint::{Checkpoint, Journal};
+use std::fmt;
+use std::iter::{FusedIterator, Rev};
+
+/// When a [`Stack`] gives memory back after elements are removed (REQ-9)
+/// 
+/// `Vec` never shrinks by itself, so a stack that briefly held millions of
+/// items would otherwise keep that memory forever. The shrinking policies
+/// halve the capacity once fewer than a quarter of the slots are in use; the
+/// gap between the two thresholds means a push/pop sequence hovering around
+/// one boundary cannot reallocate on every call, keeping REQ-2 amortized O(1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShrinkPolicy {
+    /// Keep all capacity until the stack is dropped (the `Vec` behaviour)
+    #[default]
+    Never,
+    /// Halve the capacity whenever `len < capacity / 4`
+    Quarter,
+    /// Like [`Quarter`](ShrinkPolicy::Quarter), but never shrink below this many slots
+    Floor(usize),
+}
 
 /// A Last-In-First-Out (LIFO) stack data structure
 /// 
@@ -16,11 +43,22 @@
 /// This stack provides compile-time memory safety through Rust's ownership system:
 /// - Values are moved in/out, preventing use-after-free bugs
 /// - Borrowing rules prevent data races and memory corruption
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct Stack<T> {
     /// Internal storage using Vec<T> for dynamic sizing and efficient operations
     /// Vec provides the amortized O(1) push and O(1) pop we need
     items: Vec<T>,
+    /// Undo log for open checkpoints (REQ-7); inert when no checkpoint is open
+    pub(crate) journal: Journal<T>,
+    /// When to release capacity after removals (REQ-9)
+    shrink: ShrinkPolicy,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Stack<T> {
+    // Only the contents are shown; the checkpoint journal is an implementation detail
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stack").field("items", &self.items).finish()
+    }
 }
 
 impl<T> Stack<T> {
@@ -33,7 +71,7 @@ impl<T> Stack<T> {
     /// assert!(stack.is_empty());
     /// ```
     pub fn new() -> Self { 
-        Self { items: Vec::new() } 
+        Self { items: Vec::new(), journal: Journal::default(), shrink: ShrinkPolicy::Never } 
     }
     
     /// Creates a new stack with pre-allocated capacity
@@ -51,7 +89,7 @@ impl<T> Stack<T> {
     /// // Can push 100 items without reallocating memory
     /// ```
     pub fn with_capacity(n: usize) -> Self { 
-        Self { items: Vec::with_capacity(n) } 
+        Self { items: Vec::with_capacity(n), journal: Journal::default(), shrink: ShrinkPolicy::Never } 
     }
 
     /// Pushes a value onto the top of the stack (REQ-2: Amortized O(1))
@@ -73,6 +111,7 @@ impl<T> Stack<T> {
     /// ```
     pub fn push(&mut self, x: T) {
         self.items.push(x); // Delegate to Vec's push (amortized O(1))
+        self.journal.record_push(1);
     }
 
     /// Pops and returns the top value from the stack (REQ-3: O(1) with ownership transfer)
@@ -95,7 +134,10 @@ impl<T> Stack<T> {
     /// assert!(stack.pop().is_none()); // Stack is empty
     /// ```
     pub fn pop(&mut self) -> Option<T> {
-        self.items.pop() // Vec::pop() transfers ownership out
+        let x = self.items.pop()?; // Vec::pop() transfers ownership out
+        self.journal.record_pop(&x);
+        self.maybe_shrink();
+        Some(x)
     }
 
     /// Returns an immutable reference to the top value without removing it (REQ-5)
@@ -151,6 +193,9 @@ impl<T> Stack<T> {
     /// assert_eq!(*stack.peek().unwrap(), 99); // Now we can borrow again
     /// ```
     pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if let Some(top) = self.items.len().checked_sub(1) {
+            self.journal.record_replace(top, &self.items[top]);
+        }
         self.items.last_mut() // Vec::last_mut() returns Option<&mut T>
     }
 
@@ -185,4 +230,585 @@ impl<T> Stack<T> {
     pub fn is_empty(&self) -> bool { 
         self.items.is_empty() 
     }
+
+    /// Returns how many elements the stack can hold without reallocating
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack: Stack<u8> = Stack::with_capacity(16);
+    /// assert!(stack.capacity() >= 16);
+    /// stack.push(1);
+    /// assert!(stack.capacity() >= 16); // No reallocation needed
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
+    /// Returns the current shrink policy (REQ-9)
+    pub fn shrink_policy(&self) -> ShrinkPolicy {
+        self.shrink
+    }
+
+    /// Chooses when the stack releases memory after pops (REQ-9)
+    /// 
+    /// The policy is applied immediately, so a stack that is already mostly
+    /// empty shrinks right away. Shrinking happens after `pop` and
+    /// `rollback_to`; `drain` keeps its capacity, like `Vec::drain`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::{ShrinkPolicy, Stack};
+    /// let mut stack: Stack<u32> = (0..1_000).collect();
+    /// stack.set_shrink_policy(ShrinkPolicy::Floor(64));
+    /// 
+    /// while stack.len() > 10 {
+    ///     stack.pop();
+    /// }
+    /// assert!(stack.capacity() < 1_000);  // Memory was given back...
+    /// assert!(stack.capacity() >= 64);    // ...but never below the floor
+    /// ```
+    pub fn set_shrink_policy(&mut self, policy: ShrinkPolicy) {
+        self.shrink = policy;
+        self.maybe_shrink();
+    }
+
+    /// Halves the capacity while fewer than a quarter of the slots are used, down to the floor
+    fn maybe_shrink(&mut self) {
+        let floor = match self.shrink {
+            ShrinkPolicy::Never => return,
+            ShrinkPolicy::Quarter => 0,
+            ShrinkPolicy::Floor(n) => n,
+        };
+        let len = self.items.len();
+        let mut target = self.items.capacity();
+        // Halve on paper first, so a big drop (e.g. a rollback) reallocates only once
+        while len < target / 4 {
+            let next = (target / 2).max(floor);
+            if next >= target {
+                break;
+            }
+            target = next;
+        }
+        if target < self.items.capacity() {
+            self.items.shrink_to(target);
+        }
+    }
+
+    /// Rolls the stack back to the state it had when `cp` was taken (REQ-7)
+    /// 
+    /// Pushed values are dropped, popped values are restored and in-place
+    /// edits are reverted, in O(number of changes since the checkpoint).
+    /// Any checkpoints opened after `cp` are discarded as well.
+    /// 
+    /// # Panics
+    /// Panics if `cp` is no longer open (for example because an enclosing
+    /// checkpoint was already rolled back or committed).
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack = Stack::new();
+    /// stack.push('(');
+    /// 
+    /// let cp = stack.checkpoint();
+    /// stack.pop();
+    /// stack.push('[');
+    /// stack.rollback_to(cp);
+    /// 
+    /// assert_eq!(stack.pop(), Some('('));
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn rollback_to(&mut self, cp: Checkpoint) {
+        self.journal.rollback(&mut self.items, cp);
+        self.maybe_shrink();
+    }
+
+    /// Accepts every change made since `cp` was taken (REQ-7)
+    /// 
+    /// Checkpoints opened after `cp` are committed too. If `cp` was the
+    /// outermost checkpoint the journal is released and journaling stops;
+    /// otherwise the changes stay undoable by the enclosing checkpoint.
+    /// 
+    /// # Panics
+    /// Panics if `cp` is no longer open.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack = Stack::new();
+    /// let cp = stack.checkpoint();
+    /// stack.push(1);
+    /// stack.commit(cp);
+    /// assert_eq!(stack.peek(), Some(&1));
+    /// assert_eq!(stack.open_checkpoints(), 0);
+    /// ```
+    pub fn commit(&mut self, cp: Checkpoint) {
+        self.journal.commit(cp);
+    }
+
+    /// Returns how many checkpoints are currently open (REQ-7)
+    pub fn open_checkpoints(&self) -> usize {
+        self.journal.depth()
+    }
+
+    /// Returns an iterator over immutable references, from top to bottom (REQ-6)
+    /// 
+    /// The first item yielded is the one `peek()` would return, so iteration
+    /// follows the same LIFO order that repeated `pop()` calls would produce -
+    /// without removing anything from the stack.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack = Stack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// stack.push(3);
+    /// 
+    /// let seen: Vec<&i32> = stack.iter().collect();
+    /// assert_eq!(seen, vec![&3, &2, &1]); // Top first
+    /// assert_eq!(stack.len(), 3);          // Nothing was removed
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { inner: self.items.iter().rev() }
+    }
+
+    /// Returns an iterator over mutable references, from top to bottom (REQ-6)
+    /// 
+    /// # Borrowing Rules
+    /// The iterator holds a mutable borrow of the whole stack, so no other
+    /// access (not even `peek()`) is allowed until it is dropped.
+    /// 
+    /// # Checkpoints
+    /// While a checkpoint is open, each element is saved to the journal as it
+    /// is yielded, so the cost is O(elements visited) rather than O(len).
+    /// A visited element is saved even if it is not written to.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack = Stack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// 
+    /// for item in stack.iter_mut() {
+    ///     *item *= 10;
+    /// }
+    /// assert_eq!(stack.pop(), Some(20));
+    /// assert_eq!(stack.pop(), Some(10));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        // Only borrow the journal when a checkpoint needs the old values
+        let journal = if self.journal.is_recording() { Some(&mut self.journal) } else { None };
+        IterMut { inner: self.items.iter_mut().enumerate().rev(), journal }
+    }
+
+    /// Removes every element, yielding them by value from top to bottom (REQ-6)
+    /// 
+    /// # Ownership
+    /// Each yielded item is MOVED out to the caller, exactly as if it had been
+    /// popped. The stack keeps its allocated capacity and is empty afterwards,
+    /// even if the `Drain` is dropped before being fully consumed.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack: Stack<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+    /// 
+    /// let drained: Vec<String> = stack.drain().collect();
+    /// assert_eq!(drained, vec!["b", "a"]);
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        for x in self.items.iter().rev() {
+            self.journal.record_pop(x);
+        }
+        Drain { inner: self.items.drain(..).rev() }
+    }
+
+    /// Pops the top `k` values at once, returned in LIFO order (REQ-10)
+    /// 
+    /// All or nothing: if fewer than `k` values are present, nothing is
+    /// removed and `None` is returned, just like `pop()` on an empty stack.
+    /// 
+    /// # Ownership
+    /// Every returned value is MOVED out to the caller, exactly as if it had
+    /// been popped individually; index 0 is the former top.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack: Stack<i32> = (1..=5).collect();
+    /// assert_eq!(stack.pop_n(2), Some(vec![5, 4]));
+    /// assert_eq!(stack.pop_n(4), None); // Only 3 left - nothing removed
+    /// assert_eq!(stack.len(), 3);
+    /// ```
+    pub fn pop_n(&mut self, k: usize) -> Option<Vec<T>> {
+        let start = self.items.len().checked_sub(k)?;
+        for x in self.items[start..].iter().rev() {
+            self.journal.record_pop(x);
+        }
+        let popped = self.items.drain(start..).rev().collect();
+        self.maybe_shrink();
+        Some(popped)
+    }
+
+    /// Borrows the top `k` values without removing them, from top to bottom (REQ-10)
+    /// 
+    /// Returns `None` if fewer than `k` values are present.
+    /// 
+    /// # Borrowing Rules
+    /// Like `peek()`, the view is an immutable borrow: the stack cannot be
+    /// modified while it is alive.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let stack: Stack<char> = "([{".chars().collect();
+    /// let top_two: Vec<&char> = stack.peek_n(2).unwrap().collect();
+    /// assert_eq!(top_two, vec![&'{', &'[']);
+    /// assert!(stack.peek_n(4).is_none());
+    /// ```
+    pub fn peek_n(&self, k: usize) -> Option<Iter<'_, T>> {
+        let start = self.items.len().checked_sub(k)?;
+        Some(Iter { inner: self.items[start..].iter().rev() })
+    }
+
+    /// Drops values from the top until at most `len` remain (REQ-10)
+    /// 
+    /// Has no effect if the stack already holds `len` values or fewer.
+    /// 
+    /// # Ownership
+    /// The removed values are dropped; use [`pop_n`](Stack::pop_n) or
+    /// [`split_off`](Stack::split_off) to keep them.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack: Stack<i32> = (1..=5).collect();
+    /// stack.truncate(2);
+    /// assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.items.len() {
+            return;
+        }
+        for x in self.items[len..].iter().rev() {
+            self.journal.record_pop(x);
+        }
+        self.items.truncate(len);
+        self.maybe_shrink();
+    }
+
+    /// Moves the top `depth` values into a new stack, keeping their order (REQ-10)
+    /// 
+    /// The returned stack has the former top on top and uses the same
+    /// shrink policy; it starts with no open checkpoints.
+    /// 
+    /// # Ownership
+    /// The values are MOVED, not cloned: afterwards they belong to the
+    /// returned stack only.
+    /// 
+    /// # Panics
+    /// Panics if `depth > len()`, like `Vec::split_off`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack: Stack<i32> = (1..=5).collect();
+    /// let top = stack.split_off(2);
+    /// assert_eq!(top.iter().copied().collect::<Vec<_>>(), vec![5, 4]);
+    /// assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn split_off(&mut self, depth: usize) -> Stack<T> {
+        let len = self.items.len();
+        assert!(depth <= len, "split_off depth {} exceeds stack length {}", depth, len);
+        for x in self.items[len - depth..].iter().rev() {
+            self.journal.record_pop(x);
+        }
+        let items = self.items.split_off(len - depth);
+        self.maybe_shrink();
+        Stack { items, journal: Journal::default(), shrink: self.shrink }
+    }
+
+    /// Moves every value of `other` onto the top of this stack, keeping their order (REQ-10)
+    /// 
+    /// `other`'s top becomes the new top, so `a.append(&mut a.split_off(k))`
+    /// restores `a`. Open checkpoints on either stack record the move.
+    /// 
+    /// # Ownership
+    /// The values are MOVED out of `other`, which is left empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut base: Stack<i32> = (1..=2).collect();
+    /// let mut more: Stack<i32> = (3..=4).collect();
+    /// base.append(&mut more);
+    /// assert!(more.is_empty());
+    /// assert_eq!(base.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    /// ```
+    pub fn append(&mut self, other: &mut Stack<T>) {
+        for x in other.items.iter().rev() {
+            other.journal.record_pop(x);
+        }
+        self.journal.record_push(other.items.len());
+        self.items.append(&mut other.items);
+        other.maybe_shrink();
+    }
+}
+
+impl<T: Clone> Stack<T> {
+    /// Marks the current state so it can be restored later (REQ-7)
+    /// 
+    /// Checkpoints nest: each call opens a new innermost mark. While any
+    /// checkpoint is open, pops keep a clone of the removed value (the caller
+    /// still receives the original) and `peek_mut`/`iter_mut` save the values
+    /// they expose, so the journal grows with the number of changes, not with
+    /// the size of the stack.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack: Stack<i32> = (1..=3).collect();
+    /// 
+    /// let cp = stack.checkpoint();
+    /// while stack.pop().is_some() {} // Speculative work
+    /// stack.rollback_to(cp);         // ...that turned out to be wrong
+    /// 
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.peek(), Some(&3));
+    /// ```
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        self.journal.open(T::clone)
+    }
+}
+
+/// Immutable iterator over a [`Stack`], yielding items from top to bottom.
+/// 
+/// Created by [`Stack::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    inner: Rev<std::slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Mutable iterator over a [`Stack`], yielding items from top to bottom.
+/// 
+/// Created by [`Stack::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: Rev<std::iter::Enumerate<std::slice::IterMut<'a, T>>>,
+    /// Set while a checkpoint is open; each element is journaled as it is handed out
+    journal: Option<&'a mut Journal<T>>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    fn record(&mut self, (index, item): (usize, &'a mut T)) -> &'a mut T {
+        if let Some(journal) = self.journal.as_mut() {
+            journal.record_replace(index, item);
+        }
+        item
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.inner.next()?;
+        Some(self.record(next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = self.inner.next_back()?;
+        Some(self.record(next))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// Owning iterator over a [`Stack`], yielding items from top to bottom.
+/// 
+/// Created by the by-value [`IntoIterator`] implementation; equivalent to
+/// popping until the stack is empty.
+#[derive(Debug, Clone)]
+pub struct IntoIter<T> {
+    inner: Rev<std::vec::IntoIter<T>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// Draining iterator over a [`Stack`], yielding items from top to bottom.
+/// 
+/// Created by [`Stack::drain`].
+#[derive(Debug)]
+pub struct Drain<'a, T> {
+    inner: Rev<std::vec::Drain<'a, T>>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the stack, yielding items from top to bottom (REQ-6)
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack = Stack::new();
+    /// stack.push("bottom");
+    /// stack.push("top");
+    /// 
+    /// let order: Vec<&str> = stack.into_iter().collect();
+    /// assert_eq!(order, vec!["top", "bottom"]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { inner: self.items.into_iter().rev() }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Stack<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    /// Builds a stack by pushing every item in iteration order (REQ-6)
+    /// 
+    /// The LAST item produced by the iterator ends up on top, so collecting
+    /// a stack's own `iter()` output into a new stack reverses it.
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let stack: Stack<i32> = (1..=3).collect();
+    /// assert_eq!(stack.peek(), Some(&3));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self { items: iter.into_iter().collect(), journal: Journal::default(), shrink: ShrinkPolicy::Never }
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    /// Pushes every item in iteration order; the last item becomes the top
+    /// 
+    /// # Examples
+    /// ```
+    /// use mission1::Stack;
+    /// let mut stack = Stack::new();
+    /// stack.push(0);
+    /// stack.extend(vec![1, 2]);
+    /// assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![2, 1, 0]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let before = self.items.len();
+        self.items.extend(iter);
+        self.journal.record_push(self.items.len() - before);
+    }
+}
+
+/// Serde support (REQ-11), enabled with the `serde` feature
+/// 
+/// A stack is written as a plain sequence from bottom to top, so the JSON for
+/// a stack built by pushing 1, 2, 3 is `[1,2,3]` and deserializing pushes the
+/// elements back in that order. Open checkpoints and the shrink policy are
+/// runtime state and are not serialized.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::Stack;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T: Serialize> Serialize for Stack<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(&self.items)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Stack<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Vec::deserialize(deserializer).map(Stack::from_iter)
+        }
+    }
 }
@@ -0,0 +1,415 @@
+// Undo/redo history built from two stacks of commands
+//
+// Executed commands are pushed onto the undo stack. Undoing pops a step,
+// reverts it and pushes it onto the redo stack; redoing moves it back. Any new
+// command invalidates the redo stack. Each step is a group of commands so a
+// transaction (e.g. "replace all") undoes in one go.
+//
+// REQ-U1: Undo and redo shall move steps between two stacks; executing a new
+//         command shall clear the redo stack
+// REQ-U2: Commands executed inside a transaction shall undo and redo as one step
+// REQ-U3: Consecutive commands shall be merged when Command::merge accepts them
+// REQ-U4: The history shall hold at most `limit` steps, discarding the oldest in O(1)
+
+use crate::{BoundedStack, OverflowPolicy, Stack};
+
+/// A reversible edit of some target (a document, a buffer, a model, ...)
+///
+/// To keep several kinds of edit in one history, make `Self` an enum.
+pub trait Command {
+    /// The state the command edits
+    type Target;
+
+    /// Performs the edit
+    fn apply(&mut self, target: &mut Self::Target);
+
+    /// Undoes exactly what [`apply`](Command::apply) did
+    fn revert(&mut self, target: &mut Self::Target);
+
+    /// Tries to absorb `next`, which has already been applied right after `self`
+    ///
+    /// Typing "abc" as three inserts can then undo as a single step. The merged
+    /// command's `revert` must undo both edits.
+    ///
+    /// # Returns
+    /// * `Ok(())` - `next` was merged into `self`
+    /// * `Err(next)` - Not mergeable; ownership is handed back (the default)
+    fn merge(&mut self, next: Self) -> Result<(), Self>
+    where
+        Self: Sized,
+    {
+        Err(next)
+    }
+}
+
+/// An undo/redo history for commands of type `C`
+///
+/// # Type Parameters
+/// * `C` - The command type; every step is a non-empty `Vec<C>` applied in order
+///
+/// # Storage
+/// The undo side is a [`BoundedStack`] with [`OverflowPolicy::DropBottom`], so
+/// once the history limit is reached the oldest step is discarded in O(1)
+/// (REQ-U4). The redo side is a plain [`Stack`]; it can never grow beyond the
+/// limit because it is only filled by undoing.
+///
+/// # Examples
+/// ```
+/// use mission1::undo::{Command, UndoHistory};
+///
+/// struct Add(i32);
+///
+/// impl Command for Add {
+///     type Target = i32;
+///     fn apply(&mut self, total: &mut i32) { *total += self.0; }
+///     fn revert(&mut self, total: &mut i32) { *total -= self.0; }
+/// }
+///
+/// let mut total = 0;
+/// let mut history = UndoHistory::with_limit(100);
+/// history.execute(&mut total, Add(5));
+///
+/// history.begin_transaction();
+/// history.execute(&mut total, Add(1));
+/// history.execute(&mut total, Add(2));
+/// history.commit_transaction();
+/// assert_eq!(total, 8);
+///
+/// assert!(history.undo(&mut total)); // Undoes both commands of the transaction
+/// assert_eq!(total, 5);
+/// assert!(history.redo(&mut total));
+/// assert_eq!(total, 8);
+/// ```
+#[derive(Debug)]
+pub struct UndoHistory<C> {
+    undo: BoundedStack<Vec<C>>,
+    redo: Stack<Vec<C>>,
+    /// Commands of the transaction being built
+    open: Vec<C>,
+    /// Start of each nested transaction within `open`, innermost on top
+    marks: Stack<usize>,
+    /// Whether the next command may merge into the previous one (REQ-U3)
+    mergeable: bool,
+}
+
+impl<C: Command> UndoHistory<C> {
+    /// Creates an empty history keeping at most `limit` undo steps
+    ///
+    /// # Panics
+    /// Panics if `limit` is 0.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            undo: BoundedStack::with_policy(limit, OverflowPolicy::DropBottom),
+            redo: Stack::new(),
+            open: Vec::new(),
+            marks: Stack::new(),
+            mergeable: false,
+        }
+    }
+
+    /// Applies `cmd` to `target` and records it (REQ-U1, REQ-U3)
+    ///
+    /// Clears the redo history; inside a transaction that waits for the
+    /// outermost commit, so an aborted transaction leaves redo intact. If the
+    /// previous command is still open for merging, `cmd` is offered to its
+    /// [`Command::merge`] first.
+    pub fn execute(&mut self, target: &mut C::Target, mut cmd: C) {
+        cmd.apply(target);
+
+        let in_transaction = !self.marks.is_empty();
+        if !in_transaction {
+            self.redo = Stack::new();
+        }
+        let previous = if in_transaction {
+            let start = self.marks.peek().copied().unwrap_or(0);
+            self.open[start..].last_mut()
+        } else {
+            // Only single-command steps merge, never whole transactions
+            self.undo.peek_mut().filter(|step| step.len() == 1).and_then(|step| step.last_mut())
+        };
+        let cmd = match previous {
+            Some(prev) if self.mergeable => match prev.merge(cmd) {
+                Ok(()) => return,
+                Err(cmd) => cmd,
+            },
+            _ => cmd,
+        };
+
+        if in_transaction {
+            self.open.push(cmd);
+        } else {
+            self.push_step(vec![cmd]);
+        }
+        self.mergeable = true;
+    }
+
+    /// Reverts the most recent step (REQ-U1)
+    ///
+    /// # Returns
+    /// `false` if there was nothing to undo
+    ///
+    /// # Panics
+    /// Panics if a transaction is open.
+    pub fn undo(&mut self, target: &mut C::Target) -> bool {
+        assert!(self.marks.is_empty(), "cannot undo while a transaction is open");
+        let Some(mut step) = self.undo.pop() else { return false };
+        for cmd in step.iter_mut().rev() {
+            cmd.revert(target);
+        }
+        self.redo.push(step);
+        self.mergeable = false;
+        true
+    }
+
+    /// Re-applies the most recently undone step (REQ-U1)
+    ///
+    /// # Returns
+    /// `false` if there was nothing to redo
+    ///
+    /// # Panics
+    /// Panics if a transaction is open.
+    pub fn redo(&mut self, target: &mut C::Target) -> bool {
+        assert!(self.marks.is_empty(), "cannot redo while a transaction is open");
+        let Some(mut step) = self.redo.pop() else { return false };
+        for cmd in step.iter_mut() {
+            cmd.apply(target);
+        }
+        self.push_step(step);
+        self.mergeable = false;
+        true
+    }
+
+    /// Starts grouping commands into a single undo step (REQ-U2)
+    ///
+    /// Transactions nest; only the outermost commit creates a step.
+    pub fn begin_transaction(&mut self) {
+        self.marks.push(self.open.len());
+        self.mergeable = false;
+    }
+
+    /// Closes the innermost transaction, keeping its commands
+    ///
+    /// # Panics
+    /// Panics if no transaction is open.
+    pub fn commit_transaction(&mut self) {
+        self.marks.pop().expect("no transaction is open");
+        if self.marks.is_empty() && !self.open.is_empty() {
+            let step = std::mem::take(&mut self.open);
+            self.push_step(step);
+            self.redo = Stack::new();
+        }
+        self.mergeable = false;
+    }
+
+    /// Closes the innermost transaction, reverting and discarding its commands
+    ///
+    /// # Panics
+    /// Panics if no transaction is open.
+    pub fn abort_transaction(&mut self, target: &mut C::Target) {
+        let start = self.marks.pop().expect("no transaction is open");
+        while self.open.len() > start {
+            if let Some(mut cmd) = self.open.pop() {
+                cmd.revert(target);
+            }
+        }
+        self.mergeable = false;
+    }
+
+    /// Prevents the next command from merging into the previous one
+    ///
+    /// Editors call this on cursor moves or after a pause in typing.
+    pub fn break_merge(&mut self) {
+        self.mergeable = false;
+    }
+
+    /// Number of steps that can be undone
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Number of steps that can be redone
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Maximum number of undo steps kept
+    pub fn limit(&self) -> usize {
+        self.undo.capacity()
+    }
+
+    /// Returns true while a transaction is open
+    pub fn in_transaction(&self) -> bool {
+        !self.marks.is_empty()
+    }
+
+    fn push_step(&mut self, step: Vec<C>) {
+        // DropBottom never refuses: the oldest step is evicted instead (REQ-U4)
+        let _ = self.undo.try_push(step);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Text edits on a String, merging consecutive typing
+    #[derive(Debug)]
+    enum Edit {
+        Insert { at: usize, text: String },
+        Delete { at: usize, removed: String, len: usize },
+    }
+
+    impl Command for Edit {
+        type Target = String;
+
+        fn apply(&mut self, doc: &mut String) {
+            match self {
+                Edit::Insert { at, text } => doc.insert_str(*at, text),
+                Edit::Delete { at, removed, len } => *removed = doc.drain(*at..*at + *len).collect(),
+            }
+        }
+
+        fn revert(&mut self, doc: &mut String) {
+            match self {
+                Edit::Insert { at, text } => {
+                    doc.drain(*at..*at + text.len());
+                }
+                Edit::Delete { at, removed, .. } => doc.insert_str(*at, removed),
+            }
+        }
+
+        fn merge(&mut self, next: Self) -> Result<(), Self> {
+            match (self, next) {
+                (Edit::Insert { at, text }, Edit::Insert { at: next_at, text: next_text })
+                    if next_at == *at + text.len() =>
+                {
+                    text.push_str(&next_text);
+                    Ok(())
+                }
+                (_, next) => Err(next),
+            }
+        }
+    }
+
+    fn insert(at: usize, text: &str) -> Edit {
+        Edit::Insert { at, text: text.to_string() }
+    }
+
+    fn delete(at: usize, len: usize) -> Edit {
+        Edit::Delete { at, removed: String::new(), len }
+    }
+
+    #[test] // REQ-U1
+    fn undo_redo_round_trip_and_new_command_clears_redo() {
+        let mut doc = String::new();
+        let mut h = UndoHistory::with_limit(10);
+        h.execute(&mut doc, insert(0, "hello"));
+        h.break_merge();
+        h.execute(&mut doc, insert(5, " world"));
+        h.execute(&mut doc, delete(0, 1));
+        assert_eq!(doc, "ello world");
+
+        assert!(h.undo(&mut doc));
+        assert!(h.undo(&mut doc));
+        assert_eq!(doc, "hello");
+        assert!(h.redo(&mut doc));
+        assert_eq!(doc, "hello world");
+        assert_eq!(h.redo_len(), 1);
+
+        h.execute(&mut doc, insert(0, ">"));
+        assert_eq!(h.redo_len(), 0);
+        assert!(!h.redo(&mut doc));
+        while h.undo(&mut doc) {}
+        assert_eq!(doc, "");
+    }
+
+    #[test] // REQ-U2
+    fn transactions_undo_as_one_step() {
+        let mut doc = String::from("a-b-c");
+        let mut h = UndoHistory::with_limit(10);
+        h.begin_transaction();
+        h.execute(&mut doc, delete(1, 1));
+        h.begin_transaction(); // Nested: folded into the outer step
+        h.execute(&mut doc, delete(2, 1));
+        h.commit_transaction();
+        assert_eq!(h.undo_len(), 0);
+        h.commit_transaction();
+        assert_eq!(doc, "abc");
+        assert_eq!(h.undo_len(), 1);
+
+        assert!(h.undo(&mut doc));
+        assert_eq!(doc, "a-b-c");
+        assert!(h.redo(&mut doc));
+        assert_eq!(doc, "abc");
+    }
+
+    #[test] // REQ-U2
+    fn aborted_transaction_leaves_no_trace() {
+        let mut doc = String::from("keep");
+        let mut h = UndoHistory::with_limit(10);
+        h.begin_transaction();
+        h.execute(&mut doc, insert(4, "!!"));
+        h.execute(&mut doc, delete(0, 1));
+        h.abort_transaction(&mut doc);
+        assert_eq!(doc, "keep");
+        assert!(!h.in_transaction());
+        assert!(!h.undo(&mut doc));
+    }
+
+    #[test] // REQ-U1, REQ-U2
+    fn aborted_transaction_keeps_redo_history() {
+        let mut doc = String::new();
+        let mut h = UndoHistory::with_limit(10);
+        h.execute(&mut doc, insert(0, "abc"));
+        assert!(h.undo(&mut doc));
+
+        h.begin_transaction();
+        h.execute(&mut doc, insert(0, "x"));
+        h.abort_transaction(&mut doc);
+        assert_eq!(h.redo_len(), 1);
+        assert!(h.redo(&mut doc));
+        assert_eq!(doc, "abc");
+
+        // A committed transaction is a new step and does clear redo
+        assert!(h.undo(&mut doc));
+        h.begin_transaction();
+        h.execute(&mut doc, insert(0, "y"));
+        assert_eq!(h.redo_len(), 1);
+        h.commit_transaction();
+        assert_eq!(h.redo_len(), 0);
+    }
+
+    #[test] // REQ-U3
+    fn consecutive_typing_merges_until_broken() {
+        let mut doc = String::new();
+        let mut h = UndoHistory::with_limit(10);
+        for (i, ch) in "abc".chars().enumerate() {
+            h.execute(&mut doc, insert(i, &ch.to_string()));
+        }
+        h.execute(&mut doc, insert(0, ">")); // Not adjacent: a new step
+        h.break_merge();
+        h.execute(&mut doc, insert(4, "d")); // Adjacent, but merging was broken
+        assert_eq!(doc, ">abcd");
+        assert_eq!(h.undo_len(), 3);
+
+        h.undo(&mut doc);
+        h.undo(&mut doc);
+        assert_eq!(doc, "abc");
+        h.undo(&mut doc);
+        assert_eq!(doc, "");
+    }
+
+    #[test] // REQ-U4
+    fn history_limit_discards_oldest_steps() {
+        let mut doc = String::new();
+        let mut h = UndoHistory::with_limit(3);
+        for i in 0..5 {
+            h.break_merge();
+            h.execute(&mut doc, insert(i, &i.to_string()));
+        }
+        assert_eq!(h.undo_len(), 3);
+        while h.undo(&mut doc) {}
+        assert_eq!(doc, "01"); // The two oldest edits can no longer be undone
+        assert_eq!(h.redo_len(), 3);
+    }
+}
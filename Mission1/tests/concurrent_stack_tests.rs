@@ -0,0 +1,134 @@
+use mission1::ConcurrentStack;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+const THREADS: usize = 8;
+const PER_THREAD: usize = 20_000;
+
+#[test] // REQ-C1, REQ-C2
+fn stress_concurrent_push_then_pop_no_loss_no_duplicates() {
+    let stack = Arc::new(ConcurrentStack::new());
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let pushers: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let stack = Arc::clone(&stack);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                for i in 0..PER_THREAD {
+                    stack.push(t * PER_THREAD + i);
+                }
+            })
+        })
+        .collect();
+    for h in pushers {
+        h.join().unwrap();
+    }
+    assert_eq!(stack.len(), THREADS * PER_THREAD);
+
+    let poppers: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let stack = Arc::clone(&stack);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                let mut got = Vec::new();
+                while let Some(x) = stack.pop() {
+                    got.push(x);
+                }
+                got
+            })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    for h in poppers {
+        for x in h.join().unwrap() {
+            assert!(seen.insert(x), "value {} popped twice", x);
+        }
+    }
+    assert_eq!(seen.len(), THREADS * PER_THREAD, "values were lost");
+    assert!(stack.is_empty());
+}
+
+#[test] // REQ-C2, REQ-C3: interleaved push/pop keeps the hazard-pointer path busy
+fn stress_mixed_push_pop_accounts_for_every_value() {
+    let stack = Arc::new(ConcurrentStack::new());
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let workers: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let stack = Arc::clone(&stack);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                let mut popped = Vec::new();
+                for i in 0..PER_THREAD {
+                    stack.push(t * PER_THREAD + i);
+                    if i % 3 != 0 {
+                        if let Some(x) = stack.pop() {
+                            popped.push(x);
+                        }
+                    }
+                }
+                popped
+            })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    for h in workers {
+        for x in h.join().unwrap() {
+            assert!(seen.insert(x), "value {} popped twice", x);
+        }
+    }
+    while let Some(x) = stack.pop() {
+        assert!(seen.insert(x), "value {} popped twice", x);
+    }
+    assert_eq!(seen.len(), THREADS * PER_THREAD, "values were lost");
+}
+
+/// Counts live instances so leaks and double drops are both detected
+struct Tracked<'a>(&'a AtomicUsize);
+
+impl<'a> Tracked<'a> {
+    fn new(live: &'a AtomicUsize) -> Self {
+        live.fetch_add(1, Ordering::SeqCst);
+        Tracked(live)
+    }
+}
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[test] // REQ-C3, REQ-C4
+fn stress_every_value_dropped_exactly_once() {
+    static LIVE: AtomicUsize = AtomicUsize::new(0);
+    {
+        let stack = Arc::new(ConcurrentStack::new());
+        let workers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let stack = Arc::clone(&stack);
+                thread::spawn(move || {
+                    for i in 0..PER_THREAD / 4 {
+                        stack.push(Tracked::new(&LIVE));
+                        if i % 2 == 0 {
+                            drop(stack.pop());
+                        }
+                    }
+                })
+            })
+            .collect();
+        for h in workers {
+            h.join().unwrap();
+        }
+        assert_eq!(LIVE.load(Ordering::SeqCst), stack.len());
+    }
+    assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+}
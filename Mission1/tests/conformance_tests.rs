@@ -0,0 +1,58 @@
+// Every single-threaded stack variant, checked against the Vec model (REQ-8, REQ-K2)
+//
+// A new variant that implements Lifo<T> only needs one more test here.
+
+use mission1::conformance::{check, Config, Probe};
+use mission1::{
+    AggregateStack, ArrayStack, BoundedStack, InlineStack, InstrumentedStack, Lifo, RecordingStack,
+    SegmentedStack, ShrinkPolicy, Stack,
+};
+
+fn conforms<S: Lifo<Probe>>(config: Config, make: impl FnMut() -> S) {
+    if let Err(failure) = check(&config, make) {
+        panic!("{}", failure);
+    }
+}
+
+#[test]
+fn stack_conforms() {
+    conforms(Config::default(), Stack::new);
+    conforms(Config::default(), || {
+        let mut s = Stack::new();
+        s.set_shrink_policy(ShrinkPolicy::Quarter);
+        s
+    });
+}
+
+#[test]
+fn inline_stack_conforms() {
+    conforms(Config::default(), InlineStack::<Probe, 4>::new);
+}
+
+#[test]
+fn aggregate_stack_conforms() {
+    let newest = |a: &Probe, b: &Probe| if a.id() > b.id() { a.clone() } else { b.clone() };
+    conforms(Config::default(), || AggregateStack::new(newest));
+}
+
+#[test]
+fn instrumented_stack_conforms() {
+    conforms(Config::default(), InstrumentedStack::new);
+}
+
+#[test]
+fn segmented_stack_conforms() {
+    conforms(Config { max_ops: 300, ..Config::default() }, SegmentedStack::new);
+}
+
+#[test]
+fn recording_stack_conforms() {
+    conforms(Config::default(), RecordingStack::new);
+}
+
+#[test]
+fn bounded_stacks_conform_with_capacity() {
+    let config = Config { capacity: Some(8), ..Config::default() };
+    conforms(config, || BoundedStack::with_capacity(8));
+    conforms(config, ArrayStack::<Probe, 8>::new);
+}
@@ -1,4 +1,4 @@
-use mission1::Stack;
+use mission1::{ShrinkPolicy, Stack};
 
 #[test] // REQ-1
 fn req1_generic_support() {
@@ -62,3 +62,246 @@ fn req5_peek_aliasing_rules() {
     }
     assert_eq!(*s.peek().unwrap(), 99);
 }
+
+
+#[test] // REQ-6
+fn req6_iter_is_lifo_and_non_destructive() {
+    let mut s = Stack::new();
+    for i in 1..=4 {
+        s.push(i);
+    }
+    let seen: Vec<i32> = s.iter().copied().collect();
+    assert_eq!(seen, vec![4, 3, 2, 1]);
+    assert_eq!(s.len(), 4);
+    assert_eq!(s.iter().len(), 4);
+    assert_eq!(s.iter().next_back(), Some(&1)); // bottom of the stack
+    assert_eq!((&s).into_iter().count(), 4);
+}
+
+#[test] // REQ-6
+fn req6_iter_mut_updates_in_place() {
+    let mut s: Stack<String> = ["a", "b"].iter().map(|x| x.to_string()).collect();
+    for item in &mut s {
+        item.push('!');
+    }
+    assert_eq!(s.pop().as_deref(), Some("b!"));
+    assert_eq!(s.pop().as_deref(), Some("a!"));
+}
+
+#[test] // REQ-6
+fn req6_into_iter_matches_repeated_pop() {
+    let mut popped = Stack::new();
+    let mut consumed = Stack::new();
+    for i in 0..10 {
+        popped.push(i);
+        consumed.push(i);
+    }
+    let mut by_pop = Vec::new();
+    while let Some(x) = popped.pop() {
+        by_pop.push(x);
+    }
+    let by_iter: Vec<i32> = consumed.into_iter().collect();
+    assert_eq!(by_iter, by_pop);
+}
+
+#[test] // REQ-6
+fn req6_drain_empties_and_transfers_ownership() {
+    let mut s: Stack<String> = (0..3).map(|i| i.to_string()).collect();
+    {
+        let mut d = s.drain();
+        assert_eq!(d.next().as_deref(), Some("2"));
+        // Dropping a partially consumed Drain still empties the stack
+    }
+    assert!(s.is_empty());
+    assert_eq!(s.pop(), None);
+}
+
+#[test] // REQ-6
+fn req6_from_iterator_and_extend_push_in_order() {
+    let mut s: Stack<i32> = vec![1, 2].into_iter().collect();
+    s.extend(3..=4);
+    assert_eq!(s.peek(), Some(&4));
+    // Collecting a stack's iteration reverses it
+    let reversed: Stack<i32> = s.iter().copied().collect();
+    assert_eq!(reversed.peek(), Some(&1));
+}
+
+#[test] // REQ-9
+fn req9_capacity_drops_after_mass_pop() {
+    let mut s: Stack<u64> = (0..1_000_000).collect();
+    let peak = s.capacity();
+
+    let mut never = Stack::with_capacity(peak);
+    never.extend(0..10u64);
+    assert_eq!(never.shrink_policy(), ShrinkPolicy::Never);
+    never.pop();
+    assert_eq!(never.capacity(), peak); // Default keeps Vec's behaviour
+
+    s.set_shrink_policy(ShrinkPolicy::Quarter);
+    while s.len() > 100 {
+        s.pop();
+    }
+    assert!(s.capacity() < 800, "capacity {} was not released", s.capacity());
+    assert!(s.capacity() >= s.len());
+
+    s.set_shrink_policy(ShrinkPolicy::Floor(4096));
+    s.extend(0..10_000);
+    while s.pop().is_some() {}
+    assert!(s.capacity() >= 4096);
+    assert!(s.capacity() < 10_000);
+}
+
+#[test] // REQ-9: shrinking must not break amortized O(1) push/pop (REQ-2)
+fn req9_shrinking_keeps_push_pop_amortized_constant() {
+    let mut s = Stack::new();
+    s.set_shrink_policy(ShrinkPolicy::Quarter);
+    let mut reallocations = 0;
+    let mut slots_moved = 0;
+    let mut ops = 0;
+    let mut track = |s: &Stack<u32>, before: usize| {
+        if s.capacity() != before {
+            reallocations += 1;
+            slots_moved += s.len();
+        }
+    };
+
+    // Grow and shrink repeatedly, then hover around every threshold
+    for round in 0..20u32 {
+        for i in 0..(1 << 12) {
+            let before = s.capacity();
+            s.push(i + round);
+            track(&s, before);
+            ops += 1;
+        }
+        while s.len() > 3 {
+            let before = s.capacity();
+            s.pop();
+            track(&s, before);
+            ops += 1;
+        }
+        for i in 0..1_000 {
+            let before = s.capacity();
+            if i % 2 == 0 { s.push(i) } else { s.pop(); }
+            track(&s, before);
+            ops += 1;
+        }
+    }
+    // Each reallocation copies at most `len` elements; the total stays linear in ops
+    assert!(slots_moved <= 4 * ops, "moved {} slots in {} ops", slots_moved, ops);
+    assert!(reallocations < ops / 100, "{} reallocations in {} ops", reallocations, ops);
+}
+
+#[test] // REQ-9, REQ-7
+fn req9_rollback_shrinks_and_restores() {
+    let mut s: Stack<u32> = (0..8).collect();
+    s.set_shrink_policy(ShrinkPolicy::Quarter);
+    let cp = s.checkpoint();
+    s.extend(0..100_000);
+    let grown = s.capacity();
+    s.rollback_to(cp);
+    assert_eq!(s.len(), 8);
+    assert!(s.capacity() < grown / 100);
+    assert!(s.iter().copied().eq((0..8).rev()));
+}
+
+#[test] // REQ-9
+fn req9_shrink_policy_round_trips() {
+    let policies = [
+        ShrinkPolicy::Never,
+        ShrinkPolicy::Quarter,
+        ShrinkPolicy::Floor(0),
+        ShrinkPolicy::Floor(1),
+        ShrinkPolicy::Floor(4096),
+        ShrinkPolicy::Floor(usize::MAX - 1),
+        ShrinkPolicy::Floor(usize::MAX),
+    ];
+    for policy in policies {
+        let mut s: Stack<u8> = (0..4).collect();
+        s.set_shrink_policy(policy);
+        assert_eq!(s.shrink_policy(), policy);
+        assert_eq!(s.split_off(2).shrink_policy(), policy, "split_off keeps {:?}", policy);
+    }
+}
+
+#[test] // REQ-10
+fn req10_pop_n_and_peek_n_are_lifo_and_all_or_nothing() {
+    let mut s: Stack<String> = (0..5).map(|i| i.to_string()).collect();
+    let view: Vec<&str> = s.peek_n(3).unwrap().map(|x| x.as_str()).collect();
+    assert_eq!(view, vec!["4", "3", "2"]);
+    assert_eq!(s.peek_n(0).unwrap().len(), 0);
+    assert!(s.peek_n(6).is_none());
+
+    assert_eq!(s.pop_n(2), Some(vec!["4".to_string(), "3".to_string()]));
+    assert_eq!(s.pop_n(4), None);
+    assert_eq!(s.len(), 3);
+    assert_eq!(s.pop_n(0), Some(vec![]));
+}
+
+#[test] // REQ-10
+fn req10_truncate_split_off_and_append() {
+    let mut s: Stack<i32> = (1..=6).collect();
+    s.truncate(10); // No-op
+    s.truncate(5);
+    assert_eq!(s.peek(), Some(&5));
+
+    let mut top = s.split_off(2);
+    assert_eq!(top.iter().copied().collect::<Vec<_>>(), vec![5, 4]);
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+    s.append(&mut top);
+    assert!(top.is_empty());
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+    assert_eq!(s.split_off(0).len(), 0);
+}
+
+#[test] // REQ-10, REQ-7
+fn req10_bulk_operations_roll_back() {
+    let mut a: Stack<i32> = (1..=6).collect();
+    let mut b: Stack<i32> = (10..=11).collect();
+    let cp_a = a.checkpoint();
+    let cp_b = b.checkpoint();
+
+    assert_eq!(a.pop_n(2), Some(vec![6, 5]));
+    a.truncate(2);
+    let mut tail = a.split_off(1);
+    a.append(&mut b);
+    b.append(&mut tail);
+
+    a.rollback_to(cp_a);
+    b.rollback_to(cp_b);
+    assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3, 2, 1]);
+    assert_eq!(b.iter().copied().collect::<Vec<_>>(), vec![11, 10]);
+}
+
+#[test]
+#[should_panic(expected = "exceeds stack length")]
+fn req10_split_off_past_bottom_panics() {
+    let mut s: Stack<u8> = Stack::new();
+    s.push(1);
+    let _ = s.split_off(2);
+}
+
+#[cfg(feature = "serde")]
+#[test] // REQ-11
+fn req11_json_round_trip_keeps_lifo_order() {
+    let mut s = Stack::new();
+    for word in ["parse", "expr", "term"] {
+        s.push(word.to_string());
+    }
+    let json = serde_json::to_string(&s).unwrap();
+    let mut back: Stack<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.len(), 3);
+    assert_eq!(back.pop().as_deref(), Some("term"));
+    assert_eq!(back.pop().as_deref(), Some("expr"));
+    assert_eq!(back.pop().as_deref(), Some("parse"));
+}
+
+#[cfg(feature = "serde")]
+#[test] // REQ-11
+fn req11_json_is_bottom_to_top() {
+    let s: Stack<(char, usize)> = vec![('(', 0), ('[', 3)].into_iter().collect();
+    assert_eq!(serde_json::to_string(&s).unwrap(), r#"[["(",0],["[",3]]"#);
+    let empty: Stack<u8> = serde_json::from_str("[]").unwrap();
+    assert!(empty.is_empty());
+    assert!(serde_json::from_str::<Stack<u8>>(r#"{"items":[1]}"#).is_err());
+}
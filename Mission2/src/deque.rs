@@ -0,0 +1,564 @@
+//! Growable double-ended queue built on a ring buffer
+//!
+//! [`RingBufferQueue`](crate::RingBufferQueue) has a fixed capacity and only
+//! enqueues at the back. `RingDeque` lifts both limits: it pushes and pops at
+//! either end and doubles its buffer when full, keeping the logical order of
+//! elements intact even when they wrap around the end of the buffer.
+
+use std::fmt;
+use std::iter::{Chain, FusedIterator};
+use std::mem::MaybeUninit;
+use std::ops::{Index, IndexMut};
+use std::{ptr, slice};
+
+/// Capacity allocated by the first push into an empty, unallocated deque
+const MIN_CAPACITY: usize = 4;
+
+/// A growable FIFO/LIFO deque stored in a single ring buffer.
+///
+/// Unlike [`RingBufferQueue`](crate::RingBufferQueue), slots are
+/// `MaybeUninit<T>` rather than `Option<T>`: the occupied part of the buffer
+/// is a plain run of `T`s, which is what lets [`as_slices`](RingDeque::as_slices)
+/// hand out real `&[T]` slices.
+///
+/// # Memory Layout
+///
+/// ```text
+/// capacity 8, head 6, len 4:
+///   index:  0   1   2   3   4   5   6   7
+///   buf:   [c] [d] [ ] [ ] [ ] [ ] [a] [b]   logical order: a b c d
+/// ```
+/// Logical position `i` lives at `(head + i) % capacity`; the elements from
+/// `head` to the end of the buffer come first, the wrapped-around ones second.
+///
+/// # Examples
+///
+/// ```rust
+/// use mission2::RingDeque;
+///
+/// let mut deque = RingDeque::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+/// assert_eq!(deque.len(), 3);
+/// assert_eq!(deque[0], 1);
+///
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.front(), Some(&2));
+/// ```
+///
+/// # Requirements Satisfied
+/// - **REQ-D1**: Capacity doubles on demand, preserving logical order across the wrap
+/// - **REQ-D2**: O(1) amortized push/pop at both ends
+/// - **REQ-D3**: Indexing by logical position
+/// - **REQ-D4**: `as_slices` / `make_contiguous` expose the storage as slices
+pub struct RingDeque<T> {
+    /// Slots `head .. head + len` (mod capacity) are initialized, the rest are not
+    buf: Vec<MaybeUninit<T>>,
+    /// Physical index of the front element
+    head: usize,
+    /// Number of initialized elements
+    len: usize,
+}
+
+impl<T> RingDeque<T> {
+    /// Creates an empty deque; nothing is allocated until the first push.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingDeque;
+    ///
+    /// let deque: RingDeque<i32> = RingDeque::new();
+    /// assert_eq!(deque.capacity(), 0);
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self { buf: Vec::new(), head: 0, len: 0 }
+    }
+
+    /// Creates an empty deque with room for `cap` elements before it grows.
+    ///
+    /// Unlike [`RingBufferQueue::with_capacity`](crate::RingBufferQueue::with_capacity)
+    /// a capacity of 0 is allowed: the deque simply allocates on the first push.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingDeque;
+    ///
+    /// let deque: RingDeque<i32> = RingDeque::with_capacity(10);
+    /// assert_eq!(deque.capacity(), 10);
+    /// ```
+    pub fn with_capacity(cap: usize) -> Self {
+        let mut buf = Vec::with_capacity(cap);
+        buf.resize_with(cap, MaybeUninit::uninit);
+        Self { buf, head: 0, len: 0 }
+    }
+
+    /// Returns the number of elements in the deque.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the deque contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns how many elements fit before the next growth.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Maps a logical position (0 = front) to its index in `buf`.
+    ///
+    /// Only meaningful for a non-zero capacity and `i < capacity`.
+    #[inline]
+    fn slot(&self, i: usize) -> usize {
+        let i = self.head + i;
+        if i >= self.capacity() {
+            i - self.capacity()
+        } else {
+            i
+        }
+    }
+
+    /// Adds an element to the back of the deque, growing it if full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingDeque;
+    ///
+    /// let mut deque = RingDeque::with_capacity(1);
+    /// deque.push_back(1);
+    /// deque.push_back(2); // grows instead of failing
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-D1**: Doubles capacity when full
+    /// - **REQ-D2**: O(1) amortized
+    pub fn push_back(&mut self, x: T) {
+        if self.len == self.capacity() {
+            self.grow();
+        }
+        let at = self.slot(self.len);
+        self.buf[at].write(x);
+        self.len += 1;
+    }
+
+    /// Adds an element to the front of the deque, growing it if full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingDeque;
+    ///
+    /// let mut deque = RingDeque::new();
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-D1**: Doubles capacity when full
+    /// - **REQ-D2**: O(1) amortized
+    pub fn push_front(&mut self, x: T) {
+        if self.len == self.capacity() {
+            self.grow();
+        }
+        self.head = self.slot(self.capacity() - 1);
+        self.buf[self.head].write(x);
+        self.len += 1;
+    }
+
+    /// Removes and returns the front element (FIFO order with `push_back`).
+    ///
+    /// # Requirements
+    /// - **REQ-D2**: O(1)
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let at = self.head;
+        self.head = self.slot(1);
+        self.len -= 1;
+        // SAFETY: `at` was the front slot, so it is initialized; it is now
+        // outside the live range and will not be read again
+        Some(unsafe { self.buf[at].assume_init_read() })
+    }
+
+    /// Removes and returns the back element (LIFO order with `push_back`).
+    ///
+    /// # Requirements
+    /// - **REQ-D2**: O(1)
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let at = self.slot(self.len);
+        // SAFETY: as in `pop_front`, for the last slot of the live range
+        Some(unsafe { self.buf[at].assume_init_read() })
+    }
+
+    /// Returns a reference to the front element.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Returns a mutable reference to the front element.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a mutable reference to the back element.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Returns the element at logical position `i` (0 = front), if any.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingDeque;
+    ///
+    /// let deque: RingDeque<_> = (1..=3).collect();
+    /// assert_eq!(deque.get(2), Some(&3));
+    /// assert_eq!(deque.get(3), None);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-D3**: Logical indexing
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.len {
+            return None;
+        }
+        // SAFETY: positions below len are initialized
+        Some(unsafe { self.buf[self.slot(i)].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at logical position `i`.
+    ///
+    /// # Requirements
+    /// - **REQ-D3**: Logical indexing
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        let at = self.slot(i);
+        // SAFETY: positions below len are initialized
+        Some(unsafe { self.buf[at].assume_init_mut() })
+    }
+
+    /// Returns the contents as two slices, front to back.
+    ///
+    /// The first slice runs from the front element to the end of the buffer
+    /// (or to the back element); the second holds the wrapped-around part
+    /// and is empty when the deque is contiguous.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingDeque;
+    ///
+    /// let mut deque = RingDeque::with_capacity(4);
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// deque.push_front(1); // wraps to the end of the buffer
+    /// assert_eq!(deque.as_slices(), (&[1][..], &[2, 3][..]));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-D4**: Two-run slice view in logical order
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.runs();
+        // SAFETY: both runs lie inside the live range, and MaybeUninit<T> has
+        // the same layout as T
+        unsafe {
+            (
+                slice::from_raw_parts(self.buf.as_ptr().add(first.0).cast(), first.1),
+                slice::from_raw_parts(self.buf.as_ptr().cast(), second),
+            )
+        }
+    }
+
+    /// Returns the contents as two mutable slices, front to back.
+    ///
+    /// # Requirements
+    /// - **REQ-D4**: Two-run slice view in logical order
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.runs();
+        // SAFETY: as in `as_slices`; the runs are disjoint because the first
+        // starts at head and the second ends before it
+        unsafe {
+            let base = self.buf.as_mut_ptr();
+            (
+                slice::from_raw_parts_mut(base.add(first.0).cast(), first.1),
+                slice::from_raw_parts_mut(base.cast(), second),
+            )
+        }
+    }
+
+    /// Rearranges the storage so all elements sit in one slice, and returns it.
+    ///
+    /// Does not allocate. After the call `as_slices().1` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingDeque;
+    ///
+    /// let mut deque = RingDeque::with_capacity(4);
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// deque.make_contiguous().sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(deque.as_slices(), (&[2, 1][..], &[][..]));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-D4**: In-place defragmentation into one slice
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.len > self.capacity() {
+            // Rotating uninitialized slots along with the live ones is fine:
+            // MaybeUninit values are moved bitwise and never dropped
+            self.buf.rotate_left(self.head);
+            self.head = 0;
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Returns a front-to-back iterator over the elements.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (a, b) = self.as_slices();
+        Iter { inner: a.iter().chain(b.iter()) }
+    }
+
+    /// Returns a front-to-back iterator that allows modifying each element.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (a, b) = self.as_mut_slices();
+        IterMut { inner: a.iter_mut().chain(b.iter_mut()) }
+    }
+
+    /// Removes all elements, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        let (a, b) = self.as_mut_slices();
+        let (a, b): (*mut [T], *mut [T]) = (a, b);
+        // Forget the elements first so a panicking destructor cannot cause a
+        // double drop; the rest of them leak in that case, as with Vec
+        self.head = 0;
+        self.len = 0;
+        // SAFETY: the slices were live and are no longer reachable through self
+        unsafe {
+            ptr::drop_in_place(a);
+            ptr::drop_in_place(b);
+        }
+    }
+
+    /// `(start, len)` of the first run and the length of the second, which starts at 0.
+    fn runs(&self) -> ((usize, usize), usize) {
+        self.runs_for(self.capacity())
+    }
+
+    /// Doubles the capacity, keeping the logical order across the wrap (REQ-D1).
+    ///
+    /// After the buffer is resized, the elements that wrapped to the start of
+    /// the old buffer are no longer behind the front run. Whichever of the
+    /// two runs is shorter gets moved so the ring is consistent again:
+    ///
+    /// ```text
+    /// old:  [C D . A B]          head = 3
+    /// new:  [C D . A B . . . . .]
+    /// fix:  [. . . A B C D . . .]  (move the wrapped run, shorter)
+    /// ```
+    #[cold]
+    fn grow(&mut self) {
+        let old_cap = self.capacity();
+        let new_cap = match old_cap {
+            0 => MIN_CAPACITY,
+            _ => old_cap.checked_mul(2).expect("capacity overflow"),
+        };
+        self.buf.resize_with(new_cap, MaybeUninit::uninit);
+
+        let ((head, first), wrapped) = self.runs_for(old_cap);
+        if wrapped == 0 {
+            return;
+        }
+        let base = self.buf.as_mut_ptr();
+        // SAFETY: new_cap >= 2 * old_cap, so either destination range is
+        // inside the buffer and does not overlap its source run
+        unsafe {
+            if wrapped <= first {
+                ptr::copy_nonoverlapping(base, base.add(old_cap), wrapped);
+            } else {
+                let new_head = new_cap - first;
+                ptr::copy_nonoverlapping(base.add(head), base.add(new_head), first);
+                self.head = new_head;
+            }
+        }
+    }
+
+    /// Like `runs`, but against an explicit capacity (used mid-growth)
+    fn runs_for(&self, cap: usize) -> ((usize, usize), usize) {
+        let to_end = cap - self.head;
+        if self.len <= to_end {
+            ((self.head, self.len), 0)
+        } else {
+            ((self.head, to_end), self.len - to_end)
+        }
+    }
+}
+
+impl<T> Drop for RingDeque<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for RingDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for RingDeque<T> {
+    fn clone(&self) -> Self {
+        let mut out = Self::with_capacity(self.len);
+        out.extend(self.iter().cloned());
+        out
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for RingDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for RingDeque<T> {}
+
+impl<T> Index<usize> for RingDeque<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `i >= len()`.
+    fn index(&self, i: usize) -> &T {
+        let len = self.len;
+        self.get(i).unwrap_or_else(|| panic!("index {} out of bounds for length {}", i, len))
+    }
+}
+
+impl<T> IndexMut<usize> for RingDeque<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(i).unwrap_or_else(|| panic!("index {} out of bounds for length {}", i, len))
+    }
+}
+
+impl<T> Extend<T> for RingDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push_back(x);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for RingDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+// SAFETY: RingDeque owns its elements exactly like Vec<T> does
+unsafe impl<T: Send> Send for RingDeque<T> {}
+unsafe impl<T: Sync> Sync for RingDeque<T> {}
+
+/// Front-to-back iterator returned by [`RingDeque::iter`]
+#[derive(Clone)]
+pub struct Iter<'a, T> {
+    inner: Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Mutable front-to-back iterator returned by [`RingDeque::iter_mut`]
+pub struct IterMut<'a, T> {
+    inner: Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a RingDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut RingDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -1,6 +1,6 @@
 //! # Mission 2: FIFO Queue Implementations
 //!
-//! This crate provides two high-performance FIFO (First-In-First-Out) queue implementations
+//! This crate provides high-performance FIFO (First-In-First-Out) queue implementations
 //! designed for different use cases in competitive programming and systems development.
 //!
 //! ## Implementations
@@ -12,6 +12,15 @@
 //! - **Perfect for bounded problems** (e.g., BFS on grids)
 //! - **Overwriting mode** for circular buffers (enqueue_overwrite)
 //!
+//! ### 🧱 **UnrolledQueue<T>**
+//! - **Unbounded** like LinkedQueue, but links blocks of 64 elements
+//! - **Cache-friendly** within a block, one allocation per block
+//!
+//! ### ↔️ **RingDeque<T>**
+//! - **Growable** ring buffer that doubles when full
+//! - **Double-ended**: push/pop at the front and the back
+//! - **Slice access** via `as_slices` and `make_contiguous`
+//!
 //! ### 🔗 **LinkedQueue<T>**
 //! - **Dynamic size** growing as needed
 //! - **No capacity limits** beyond available memory
@@ -53,6 +62,9 @@
 //! - **REQ-G3**: Memory safety without panics or use-after-free
 //! - **REQ-G4**: O(1) amortized complexity for all operations
 //! - **REQ-G5**: Deterministic behavior across platforms
+//! - **REQ-G6**: Non-destructive iteration (iter, iter_mut) and by-value IntoIterator, front to back
+//! - **REQ-G7**: `drain(range)` removes a logical range, keeping both parts in order
+//! - **REQ-G8**: `retain` / `remove_if` remove by predicate, keeping FIFO order
 //!
 //! ### Ring Buffer Specific
 //! - **REQ-R1**: Fixed capacity with backpressure (returns Err on full)
@@ -67,10 +79,31 @@
 //! - **REQ-RO4**: Maintain FIFO ordering of remaining elements
 //! - **REQ-RO5**: Consistent behavior with peek/dequeue after overwrite
 //!
+//! ### Fast Ring Buffer (`queue_fast`)
+//! - **REQ-F1**: `MaybeUninit<T>` slots in a power-of-two buffer with bitmask wrap-around
+//! - **REQ-F2**: Remaining elements are dropped exactly once; the `queue` API is kept unchanged
+//!
 //! ### Linked Queue Specific
 //! - **REQ-L1**: Singly-linked node structure with head/tail
 //! - **REQ-L2**: True O(1) operations with pointer manipulation
 //! - **REQ-L3**: Value ownership transfer without copying
+//! - **REQ-L4**: Iterative drop, safe for arbitrarily long queues
+//! - **REQ-L5**: Optional free-list node pool (`with_pool`)
+//!
+//! ### Unrolled Linked Queue
+//! - **REQ-U1**: Unbounded growth through linked fixed-size blocks
+//! - **REQ-U2**: One allocation per block, with a spare block reused
+//! - **REQ-U3**: LinkedQueue's FIFO and iteration API; iterative drop
+//!
+//! ### Growable Deque
+//! - **REQ-D1**: Capacity doubles on demand, preserving logical order across the wrap
+//! - **REQ-D2**: O(1) amortized push/pop at both ends
+//! - **REQ-D3**: Indexing by logical position (0 = front)
+//! - **REQ-D4**: `as_slices` / `make_contiguous` slice views
+//!
+//! ### Serialization (optional `serde` feature)
+//! - **REQ-S1**: Round trip preserves capacity and logical (FIFO) order
+//! - **REQ-S2**: Invalid input (capacity 0, more items than capacity, oversized capacity) is rejected
 //!
 //! ## Performance Characteristics
 //!
@@ -104,8 +137,14 @@
 //! - Algorithms with unpredictable growth patterns
 //! - When occasional allocation overhead is acceptable
 
+pub mod deque;
+mod pool;
 pub mod queue;
 pub mod queue_alt; // Alternative implementations from original specification
+pub mod queue_fast; // Same API, MaybeUninit power-of-two ring
+pub mod unrolled;
 
 // Re-export main types for convenient access
-pub use queue::{RingBufferQueue, LinkedQueue};
\ No newline at end of file
+pub use deque::RingDeque;
+pub use queue::{RingBufferQueue, LinkedQueue};
+pub use unrolled::UnrolledQueue;
\ No newline at end of file
@@ -3,7 +3,7 @@
 //! This program demonstrates the Ring Buffer and Linked Queue implementations
 //! with real-world usage scenarios including BFS frontier simulation.
 
-use mission2::{RingBufferQueue, LinkedQueue};
+use mission2::{RingBufferQueue, LinkedQueue, RingDeque, UnrolledQueue};
 use std::collections::VecDeque;
 
 fn main() {
@@ -243,6 +243,33 @@ fn demo_performance_comparison() {
     println!("Ring Buffer: {} operations in {:?}", N, ring_time);
     println!("  Processed: {} items", processed);
     
+    // Same workload on the MaybeUninit power-of-two ring (queue_fast)
+    let start = Instant::now();
+    let mut fast = mission2::queue_fast::RingBufferQueue::with_capacity(1000);
+    let mut processed = 0;
+    
+    for i in 0..N {
+        if fast.enqueue(i).is_err() {
+            for _ in 0..100 {
+                if fast.dequeue().is_some() {
+                    processed += 1;
+                }
+            }
+            fast.enqueue(i).unwrap();
+        }
+    }
+    while fast.dequeue().is_some() {
+        processed += 1;
+    }
+    
+    let fast_time = start.elapsed();
+    println!("Fast Ring:   {} operations in {:?}", N, fast_time);
+    println!("  Processed: {} items", processed);
+    println!(
+        "  Speedup over Ring Buffer: {:.2}x",
+        ring_time.as_secs_f64() / fast_time.as_secs_f64().max(f64::EPSILON)
+    );
+    
     // Linked Queue performance
     let start = Instant::now();
     let mut linked = LinkedQueue::new();
@@ -260,6 +287,28 @@ fn demo_performance_comparison() {
     println!("Linked Queue: {} operations in {:?}", N, linked_time);
     println!("  Processed: {} items", processed);
     
+    // Unrolled queue: same unbounded workload, one allocation per block
+    let start = Instant::now();
+    let mut unrolled = UnrolledQueue::new();
+    
+    for i in 0..N {
+        unrolled.enqueue(i);
+    }
+    
+    let mut processed = 0;
+    while unrolled.dequeue().is_some() {
+        processed += 1;
+    }
+    
+    let unrolled_time = start.elapsed();
+    println!("Unrolled Queue: {} operations in {:?}", N, unrolled_time);
+    println!("  Processed: {} items", processed);
+    
+    println!(
+        "  Speedup over Linked Queue: {:.2}x",
+        linked_time.as_secs_f64() / unrolled_time.as_secs_f64().max(f64::EPSILON)
+    );
+    
     // VecDeque comparison
     let start = Instant::now();
     let mut vecdeque = VecDeque::new();
@@ -277,6 +326,49 @@ fn demo_performance_comparison() {
     println!("VecDeque:     {} operations in {:?}", N, vec_time);
     println!("  Processed: {} items", processed);
     
+    // Growable ring deque, same FIFO workload
+    let start = Instant::now();
+    let mut deque = RingDeque::new();
+    
+    for i in 0..N {
+        deque.push_back(i);
+    }
+    
+    let mut processed = 0;
+    while deque.pop_front().is_some() {
+        processed += 1;
+    }
+    
+    let deque_time = start.elapsed();
+    println!("RingDeque:    {} operations in {:?}", N, deque_time);
+    println!("  Processed: {} items", processed);
+    
+    // Both ends: push alternately at front and back, then pop from the back
+    let start = Instant::now();
+    let mut vecdeque = VecDeque::new();
+    for i in 0..N {
+        if i % 2 == 0 { vecdeque.push_front(i) } else { vecdeque.push_back(i) }
+    }
+    let mut vec_sum = 0;
+    while let Some(x) = vecdeque.pop_back() {
+        vec_sum += x;
+    }
+    let vec_time = start.elapsed();
+    
+    let start = Instant::now();
+    let mut deque = RingDeque::new();
+    for i in 0..N {
+        if i % 2 == 0 { deque.push_front(i) } else { deque.push_back(i) }
+    }
+    let mut deque_sum = 0;
+    while let Some(x) = deque.pop_back() {
+        deque_sum += x;
+    }
+    let deque_time = start.elapsed();
+    
+    assert_eq!(vec_sum, deque_sum);
+    println!("Double-ended: VecDeque {:?} vs RingDeque {:?}", vec_time, deque_time);
+    
     println!();
 }
 
@@ -0,0 +1,76 @@
+//! Free-list of node allocations shared by the linked queues
+//!
+//! A steady-state workload (dequeue one, enqueue one) would otherwise free a
+//! node and allocate an identical one on every step. `NodePool` keeps up to
+//! `limit` emptied allocations and hands them back out, so after warm-up such
+//! a workload makes no allocator calls at all.
+
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// Spare `Box<N>` allocations, stored without a value
+pub(crate) struct NodePool<N> {
+    /// Most recently released first (LIFO), so reused nodes are likely cache-warm
+    free: Vec<Box<MaybeUninit<N>>>,
+    /// Maximum number of spare nodes kept; 0 disables pooling
+    limit: usize,
+}
+
+impl<N> NodePool<N> {
+    /// A pool that keeps at most `limit` spare nodes (nothing is allocated up front)
+    pub(crate) const fn new(limit: usize) -> Self {
+        Self { free: Vec::new(), limit }
+    }
+
+    /// Boxes `node`, reusing a spare allocation when one is available
+    #[inline]
+    pub(crate) fn alloc(&mut self, node: N) -> Box<N> {
+        match self.free.pop() {
+            Some(slot) => Box::write(slot, node),
+            None => Box::new(node),
+        }
+    }
+
+    /// Moves the value out of `node` and keeps its allocation if there is room
+    #[inline]
+    pub(crate) fn release(&mut self, node: Box<N>) -> N {
+        if self.free.len() >= self.limit {
+            return *node;
+        }
+        let raw = Box::into_raw(node);
+        // SAFETY: `raw` came from a live Box<N>, so reading the value is valid;
+        // the allocation is then re-owned as MaybeUninit<N>, which has the same
+        // layout and will not drop the moved-out value again
+        unsafe {
+            let value = raw.read();
+            self.free.push(Box::from_raw(raw.cast::<MaybeUninit<N>>()));
+            value
+        }
+    }
+
+    /// Number of spare nodes currently held
+    pub(crate) fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Frees every spare node
+    pub(crate) fn clear(&mut self) {
+        self.free = Vec::new();
+    }
+}
+
+impl<N> Default for NodePool<N> {
+    /// Pooling disabled
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<N> fmt::Debug for NodePool<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodePool")
+            .field("free", &self.free.len())
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
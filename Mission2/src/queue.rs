@@ -3,7 +3,28 @@
 //! This module provides two complementary queue implementations, each optimized
 //! for different use cases while maintaining the same FIFO interface.
 
+use crate::pool::NodePool;
+use std::iter::{Chain, FusedIterator};
+use std::ops::{Bound, RangeBounds};
 use std::ptr::NonNull;
+use std::slice;
+
+/// Resolves a `drain` range against the queue length, panicking like `Vec::drain`.
+pub(crate) fn drain_bounds<R: RangeBounds<usize>>(range: &R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "drain range starts at {} but ends at {}", start, end);
+    assert!(end <= len, "drain range end {} out of bounds for length {}", end, len);
+    (start, end)
+}
 
 // ==========================
 // Ring Buffer Queue
@@ -319,6 +340,319 @@ impl<T> RingBufferQueue<T> {
             None
         }
     }
+
+    /// Maps a logical position (0 = front) to its slot in the buffer.
+    #[inline]
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % self.capacity()
+    }
+
+    /// The occupied slots as two runs in front-to-back order; the second run
+    /// is the wrapped-around part at the start of the buffer (often empty).
+    fn runs(&self) -> (&[Option<T>], &[Option<T>]) {
+        if self.head + self.len <= self.capacity() {
+            (&self.buf[self.head..self.head + self.len], &[])
+        } else {
+            (&self.buf[self.head..], &self.buf[..self.tail])
+        }
+    }
+
+    /// Returns an iterator over the elements from front to back.
+    ///
+    /// The queue is not modified, so it can be printed or inspected without
+    /// dequeuing anything. The wrapped-around segment is visited after the
+    /// segment at the end of the buffer.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(3);
+    /// queue.enqueue(1).unwrap();
+    /// queue.enqueue(2).unwrap();
+    /// queue.dequeue();
+    /// queue.enqueue(3).unwrap();
+    /// queue.enqueue(4).unwrap(); // Wraps to the start of the buffer
+    ///
+    /// let seen: Vec<&i32> = queue.iter().collect();
+    /// assert_eq!(seen, vec![&2, &3, &4]);
+    /// assert_eq!(queue.len(), 3);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G6**: Non-destructive front-to-back iteration
+    pub fn iter(&self) -> RingIter<'_, T> {
+        let (front, back) = self.runs();
+        RingIter { inner: front.iter().chain(back.iter()) }
+    }
+
+    /// Returns an iterator over mutable references, from front to back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(2);
+    /// queue.enqueue(1).unwrap();
+    /// queue.enqueue(2).unwrap();
+    /// for x in queue.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(queue.dequeue(), Some(10));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G6**: Non-destructive front-to-back iteration
+    pub fn iter_mut(&mut self) -> RingIterMut<'_, T> {
+        let (head, len, tail) = (self.head, self.len, self.tail);
+        let (wrapped, rest) = self.buf.split_at_mut(head);
+        let (front, back): (&mut [Option<T>], &mut [Option<T>]) = if len <= rest.len() {
+            (&mut rest[..len], &mut [])
+        } else {
+            (rest, &mut wrapped[..tail])
+        };
+        RingIterMut { inner: front.iter_mut().chain(back.iter_mut()) }
+    }
+
+    /// Removes the elements in the logical `range` (0 = front) and returns them in order.
+    ///
+    /// The remaining elements close the gap and keep their order; the buffer
+    /// is never reallocated. The removed elements are moved out eagerly, so the
+    /// queue is already updated when this returns, even if the returned
+    /// iterator is not consumed.
+    ///
+    /// # Panics
+    /// Panics if the range is decreasing or ends past `len()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(5);
+    /// for i in 0..5 {
+    ///     queue.enqueue(i).unwrap();
+    /// }
+    /// let taken: Vec<i32> = queue.drain(1..3).collect();
+    /// assert_eq!(taken, vec![1, 2]);
+    /// assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![0, 3, 4]);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G7**: Range removal preserving the order of both parts
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::IntoIter<T> {
+        let (start, end) = drain_bounds(&range, self.len);
+        let removed = end - start;
+        let drained: Vec<T> = (start..end)
+            .map(|i| {
+                let s = self.slot(i);
+                self.buf[s].take().expect("slot inside len is occupied")
+            })
+            .collect();
+        // Shift the elements after the range towards the front
+        for i in end..self.len {
+            let (from, to) = (self.slot(i), self.slot(i - removed));
+            self.buf[to] = self.buf[from].take();
+        }
+        self.len -= removed;
+        self.tail = self.slot(self.len);
+        drained.into_iter()
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`, in their original order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(6);
+    /// for i in 0..6 {
+    ///     queue.enqueue(i).unwrap();
+    /// }
+    /// queue.retain(|x| x % 2 == 0);
+    /// assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![0, 2, 4]);
+    /// assert!(queue.enqueue(6).is_ok()); // Freed slots are reusable
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G8**: Predicate-based removal preserving FIFO order
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        self.extract(|x| !keep(x), drop);
+    }
+
+    /// Removes the elements for which `pred` returns `true` and returns them front to back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::RingBufferQueue;
+    ///
+    /// let mut jobs = RingBufferQueue::with_capacity(4);
+    /// for job in ["build", "cancel:a", "test", "cancel:b"] {
+    ///     jobs.enqueue(job).unwrap();
+    /// }
+    /// let cancelled = jobs.remove_if(|job| job.starts_with("cancel"));
+    /// assert_eq!(cancelled, vec!["cancel:a", "cancel:b"]);
+    /// assert_eq!(jobs.dequeue(), Some("build"));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G8**: Predicate-based removal preserving FIFO order
+    pub fn remove_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        self.extract(pred, |x| removed.push(x));
+        removed
+    }
+
+    /// Moves every element matching `remove` into `sink`, compacting the rest in place.
+    ///
+    /// A guard finishes the compaction even if `remove` or `sink` panics, so
+    /// the queue never keeps an empty slot inside its logical range.
+    fn extract<F: FnMut(&T) -> bool, S: FnMut(T)>(&mut self, mut remove: F, mut sink: S) {
+        struct Compact<'a, T> {
+            queue: &'a mut RingBufferQueue<T>,
+            read: usize,
+            write: usize,
+            len: usize,
+        }
+
+        impl<T> Drop for Compact<'_, T> {
+            fn drop(&mut self) {
+                // Close the gap over anything not yet visited
+                for i in self.read..self.len {
+                    let (from, to) = (self.queue.slot(i), self.queue.slot(self.write));
+                    self.queue.buf[to] = self.queue.buf[from].take();
+                    self.write += 1;
+                }
+                self.queue.len = self.write;
+                self.queue.tail = self.queue.slot(self.write);
+            }
+        }
+
+        let len = self.len;
+        let mut g = Compact { queue: self, read: 0, write: 0, len };
+        while g.read < g.len {
+            let from = g.queue.slot(g.read);
+            let matched = remove(g.queue.buf[from].as_ref().expect("slot inside len is occupied"));
+            let x = g.queue.buf[from].take();
+            g.read += 1;
+            if matched {
+                sink(x.expect("slot inside len is occupied"));
+            } else {
+                let to = g.queue.slot(g.write);
+                g.queue.buf[to] = x;
+                g.write += 1;
+            }
+        }
+    }
+}
+
+/// The occupied slots of a ring buffer, in front-to-back order
+type Slots<'a, T> = Chain<slice::Iter<'a, Option<T>>, slice::Iter<'a, Option<T>>>;
+type SlotsMut<'a, T> = Chain<slice::IterMut<'a, Option<T>>, slice::IterMut<'a, Option<T>>>;
+
+/// Front-to-back iterator over a [`RingBufferQueue`], created by [`RingBufferQueue::iter`].
+#[derive(Debug, Clone)]
+pub struct RingIter<'a, T> {
+    inner: Slots<'a, T>,
+}
+
+impl<'a, T> Iterator for RingIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next().map(|slot| slot.as_ref().expect("slot inside len is occupied"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for RingIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|slot| slot.as_ref().expect("slot inside len is occupied"))
+    }
+}
+
+impl<T> ExactSizeIterator for RingIter<'_, T> {}
+impl<T> FusedIterator for RingIter<'_, T> {}
+
+/// Front-to-back iterator over mutable references, created by [`RingBufferQueue::iter_mut`].
+#[derive(Debug)]
+pub struct RingIterMut<'a, T> {
+    inner: SlotsMut<'a, T>,
+}
+
+impl<'a, T> Iterator for RingIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next().map(|slot| slot.as_mut().expect("slot inside len is occupied"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for RingIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|slot| slot.as_mut().expect("slot inside len is occupied"))
+    }
+}
+
+impl<T> ExactSizeIterator for RingIterMut<'_, T> {}
+impl<T> FusedIterator for RingIterMut<'_, T> {}
+
+/// Owning front-to-back iterator, created by `into_iter()` on a [`RingBufferQueue`].
+#[derive(Debug)]
+pub struct RingIntoIter<T> {
+    queue: RingBufferQueue<T>,
+}
+
+impl<T> Iterator for RingIntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len(), Some(self.queue.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for RingIntoIter<T> {}
+impl<T> FusedIterator for RingIntoIter<T> {}
+
+impl<T> IntoIterator for RingBufferQueue<T> {
+    type Item = T;
+    type IntoIter = RingIntoIter<T>;
+
+    /// Consumes the queue, yielding its elements from front to back (REQ-G6)
+    fn into_iter(self) -> RingIntoIter<T> {
+        RingIntoIter { queue: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingBufferQueue<T> {
+    type Item = &'a T;
+    type IntoIter = RingIter<'a, T>;
+
+    fn into_iter(self) -> RingIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut RingBufferQueue<T> {
+    type Item = &'a mut T;
+    type IntoIter = RingIterMut<'a, T>;
+
+    fn into_iter(self) -> RingIterMut<'a, T> {
+        self.iter_mut()
+    }
 }
 
 // ==========================
@@ -389,10 +723,16 @@ struct Node<T> {
 /// - **peek**: O(1) - dereference head pointer
 /// - **Space**: Dynamic, one allocation per element
 ///
+/// A queue created with [`with_pool`](LinkedQueue::with_pool) keeps emptied
+/// nodes on a free list instead of deallocating them, so a steady
+/// dequeue/enqueue workload stops calling the allocator once warmed up.
+///
 /// # Requirements Satisfied
 /// - **REQ-L1**: Singly-linked nodes with head/tail pointers
 /// - **REQ-L2**: True O(1) operations with pointer manipulation
 /// - **REQ-L3**: Value ownership transfer without copying
+/// - **REQ-L4**: Iterative drop, safe for arbitrarily long queues
+/// - **REQ-L5**: Optional node pool recycling allocations
 #[derive(Debug, Default)]
 pub struct LinkedQueue<T> {
     /// Ownership of the first node in the queue
@@ -401,6 +741,8 @@ pub struct LinkedQueue<T> {
     tail: Option<NonNull<Node<T>>>,
     /// Current number of elements in the queue
     len: usize,
+    /// Spare node allocations (disabled unless created with `with_pool`)
+    pool: NodePool<Node<T>>,
 }
 
 impl<T> LinkedQueue<T> {
@@ -416,13 +758,53 @@ impl<T> LinkedQueue<T> {
     /// ```
     #[inline]
     pub fn new() -> Self {
+        Self::with_pool(0)
+    }
+
+    /// Creates an empty queue that keeps up to `limit` freed nodes for reuse.
+    ///
+    /// Dequeued (or removed) nodes go on a free list instead of back to the
+    /// allocator, and `enqueue` takes from that list first. A limit of 0 is
+    /// the same as [`new`](LinkedQueue::new).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::LinkedQueue;
+    ///
+    /// let mut queue = LinkedQueue::with_pool(64);
+    /// for i in 0..10 {
+    ///     queue.enqueue(i);
+    /// }
+    /// while queue.dequeue().is_some() {}
+    /// assert_eq!(queue.pooled(), 10);
+    ///
+    /// queue.enqueue(42); // Reuses a pooled node, no allocation
+    /// assert_eq!(queue.pooled(), 9);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-L5**: Optional node pool
+    #[inline]
+    pub fn with_pool(limit: usize) -> Self {
         Self {
             head: None,
             tail: None,
             len: 0,
+            pool: NodePool::new(limit),
         }
     }
 
+    /// Returns the number of spare nodes held by the pool.
+    #[inline]
+    pub fn pooled(&self) -> usize {
+        self.pool.len()
+    }
+
+    /// Frees every spare node held by the pool; the pool limit is kept.
+    pub fn shrink_pool(&mut self) {
+        self.pool.clear();
+    }
+
     /// Returns the current number of elements in the queue.
     ///
     /// # Examples
@@ -458,7 +840,8 @@ impl<T> LinkedQueue<T> {
     /// Adds an element to the back of the queue.
     ///
     /// This operation always succeeds and takes ownership of the value.
-    /// Memory is allocated for a new node to store the element.
+    /// Memory is allocated for a new node to store the element, unless the
+    /// node pool has a spare one.
     ///
     /// # Arguments
     /// * `x` - The value to enqueue (ownership is transferred)
@@ -480,7 +863,7 @@ impl<T> LinkedQueue<T> {
     /// - **REQ-L2**: O(1) append to tail
     /// - **REQ-L3**: Ownership transfer into node
     pub fn enqueue(&mut self, x: T) {
-        let mut new = Box::new(Node { elem: x, next: None });
+        let mut new = self.pool.alloc(Node { elem: x, next: None });
         let new_ptr = Some(unsafe { NonNull::new_unchecked(&mut *new) });
 
         match self.tail {
@@ -531,7 +914,7 @@ impl<T> LinkedQueue<T> {
                 self.tail = None; // Queue became empty
             }
             self.len -= 1;
-            old_head.elem // Move element out
+            self.pool.release(old_head).elem // Move element out, keep the node
         })
     }
 
@@ -556,12 +939,411 @@ impl<T> LinkedQueue<T> {
     pub fn peek(&self) -> Option<&T> {
         self.head.as_ref().map(|node| &node.elem)
     }
+
+    /// Returns an iterator over the elements from front to back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::LinkedQueue;
+    ///
+    /// let mut queue = LinkedQueue::new();
+    /// queue.enqueue("a");
+    /// queue.enqueue("b");
+    /// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&"a", &"b"]);
+    /// assert_eq!(queue.len(), 2); // Nothing was dequeued
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G6**: Non-destructive front-to-back iteration
+    pub fn iter(&self) -> LinkedIter<'_, T> {
+        LinkedIter { next: self.head.as_deref(), len: self.len }
+    }
+
+    /// Returns an iterator over mutable references, from front to back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::LinkedQueue;
+    ///
+    /// let mut queue = LinkedQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    /// queue.iter_mut().for_each(|x| *x += 1);
+    /// assert_eq!(queue.dequeue(), Some(2));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G6**: Non-destructive front-to-back iteration
+    pub fn iter_mut(&mut self) -> LinkedIterMut<'_, T> {
+        LinkedIterMut { next: self.head.as_deref_mut(), len: self.len }
+    }
+
+    /// Removes the elements in the logical `range` (0 = front) and returns them in order.
+    ///
+    /// The removed nodes are unlinked eagerly, so the queue is already updated
+    /// when this returns, even if the returned iterator is not consumed.
+    ///
+    /// # Panics
+    /// Panics if the range is decreasing or ends past `len()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::LinkedQueue;
+    ///
+    /// let mut queue = LinkedQueue::new();
+    /// for i in 0..5 {
+    ///     queue.enqueue(i);
+    /// }
+    /// assert_eq!(queue.drain(3..).collect::<Vec<_>>(), vec![3, 4]);
+    /// queue.enqueue(9); // The tail was moved back correctly
+    /// assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 9]);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G7**: Range removal preserving the order of both parts
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::IntoIter<T> {
+        let (start, end) = drain_bounds(&range, self.len);
+        let mut index = 0;
+        let mut drained = Vec::with_capacity(end - start);
+        self.extract(
+            |_| {
+                index += 1;
+                (start..end).contains(&(index - 1))
+            },
+            |x| drained.push(x),
+        );
+        drained.into_iter()
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`, in their original order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::LinkedQueue;
+    ///
+    /// let mut queue = LinkedQueue::new();
+    /// for i in 0..6 {
+    ///     queue.enqueue(i);
+    /// }
+    /// queue.retain(|x| x % 3 != 0);
+    /// assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4, 5]);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G8**: Predicate-based removal preserving FIFO order
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        self.extract(|x| !keep(x), drop);
+    }
+
+    /// Removes the elements for which `pred` returns `true` and returns them front to back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::LinkedQueue;
+    ///
+    /// let mut queue = LinkedQueue::new();
+    /// for i in 1..=5 {
+    ///     queue.enqueue(i);
+    /// }
+    /// assert_eq!(queue.remove_if(|&x| x > 3), vec![4, 5]);
+    /// queue.enqueue(6);
+    /// assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 6]);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G8**: Predicate-based removal preserving FIFO order
+    pub fn remove_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        self.extract(pred, |x| removed.push(x));
+        removed
+    }
+
+    /// Unlinks every node whose element matches `remove` and hands the element to `sink`.
+    ///
+    /// `len` is updated before `sink` runs, and the tail node is only unlinked
+    /// at the very end (after `tail` has been recomputed), so the queue stays
+    /// consistent even if `remove` or `sink` panics.
+    fn extract<F: FnMut(&T) -> bool, S: FnMut(T)>(&mut self, mut remove: F, mut sink: S) {
+        let mut removed_tail = None;
+        let mut link = &mut self.head;
+        while let Some(node) = link.as_deref() {
+            if !remove(&node.elem) {
+                link = &mut link.as_mut().expect("checked above").next;
+                continue;
+            }
+            let mut node = link.take().expect("checked above");
+            *link = node.next.take();
+            self.len -= 1;
+            let elem = self.pool.release(node).elem;
+            if link.is_none() {
+                removed_tail = Some(elem); // Handed over once `tail` is fixed
+                break;
+            }
+            sink(elem);
+        }
+
+        if let Some(elem) = removed_tail {
+            self.tail = self.find_tail();
+            sink(elem);
+        }
+    }
+
+    /// Walks from the head to the last node; O(n), only needed when the tail is unlinked.
+    fn find_tail(&mut self) -> Option<NonNull<Node<T>>> {
+        let mut cur = self.head.as_deref_mut();
+        while let Some(node) = cur {
+            if node.next.is_none() {
+                return Some(NonNull::from(node));
+            }
+            cur = node.next.as_deref_mut();
+        }
+        None
+    }
+}
+
+/// Front-to-back iterator over a [`LinkedQueue`], created by [`LinkedQueue::iter`].
+#[derive(Debug)]
+pub struct LinkedIter<'a, T> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for LinkedIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.len -= 1;
+            &node.elem
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> Clone for LinkedIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { next: self.next, len: self.len }
+    }
+}
+
+impl<T> ExactSizeIterator for LinkedIter<'_, T> {}
+impl<T> FusedIterator for LinkedIter<'_, T> {}
+
+/// Front-to-back iterator over mutable references, created by [`LinkedQueue::iter_mut`].
+#[derive(Debug)]
+pub struct LinkedIterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for LinkedIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            self.len -= 1;
+            &mut node.elem
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for LinkedIterMut<'_, T> {}
+impl<T> FusedIterator for LinkedIterMut<'_, T> {}
+
+/// Owning front-to-back iterator, created by `into_iter()` on a [`LinkedQueue`].
+#[derive(Debug)]
+pub struct LinkedIntoIter<T> {
+    queue: LinkedQueue<T>,
+}
+
+impl<T> Iterator for LinkedIntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len(), Some(self.queue.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for LinkedIntoIter<T> {}
+impl<T> FusedIterator for LinkedIntoIter<T> {}
+
+impl<T> IntoIterator for LinkedQueue<T> {
+    type Item = T;
+    type IntoIter = LinkedIntoIter<T>;
+
+    /// Consumes the queue, yielding its elements from front to back (REQ-G6)
+    fn into_iter(self) -> LinkedIntoIter<T> {
+        LinkedIntoIter { queue: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedQueue<T> {
+    type Item = &'a T;
+    type IntoIter = LinkedIter<'a, T>;
+
+    fn into_iter(self) -> LinkedIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedQueue<T> {
+    type Item = &'a mut T;
+    type IntoIter = LinkedIterMut<'a, T>;
+
+    fn into_iter(self) -> LinkedIterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> Drop for LinkedQueue<T> {
+    /// Unlinks the nodes one at a time (REQ-L4)
+    ///
+    /// The default drop of `head` would recurse once per node through
+    /// `Node::next` and overflow the stack on long queues.
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut cur = self.head.take();
+        while let Some(mut node) = cur {
+            cur = node.next.take();
+        }
+    }
 }
 
 // Memory safety for LinkedQueue - implement Send and Sync safely
 unsafe impl<T: Send> Send for LinkedQueue<T> {}
 unsafe impl<T: Sync> Sync for LinkedQueue<T> {}
 
+// ==========================
+// Serde support (feature "serde")
+// ==========================
+
+/// Largest ring capacity accepted when deserializing, unless that many items were sent.
+///
+/// A ring allocates its whole buffer up front, so the `capacity` read from
+/// untrusted input must not decide how much memory is reserved. A saved ring
+/// loads if its capacity is at most this value or equals its item count.
+///
+/// # Requirements Satisfied
+/// - **REQ-S2**: Invalid input is rejected instead of panicking or aborting
+#[cfg(feature = "serde")]
+pub const MAX_DESERIALIZED_CAPACITY: usize = 1 << 20;
+
+/// Serialize/Deserialize for both queues, enabled with the `serde` feature.
+///
+/// Only the logical contents are written, front to back; `head` and `tail`
+/// are an artifact of where the ring happened to wrap and are rebuilt on load.
+///
+/// - `RingBufferQueue` is written as `{"capacity": n, "items": [front, ..., back]}`
+///   so the capacity survives a round trip
+/// - `LinkedQueue` is written as a plain sequence `[front, ..., back]`
+///
+/// # Requirements Satisfied
+/// - **REQ-S1**: Round trip preserves capacity and logical (FIFO) order
+/// - **REQ-S2**: Invalid input (capacity 0, more items than capacity, or a
+///   capacity above [`MAX_DESERIALIZED_CAPACITY`]) is rejected
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impls {
+    use super::{LinkedQueue, RingBufferQueue, MAX_DESERIALIZED_CAPACITY};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Borrowed view written by `RingBufferQueue::serialize`
+    #[derive(Serialize)]
+    #[serde(rename = "RingBufferQueue")]
+    pub(crate) struct RingRef<'a, T> {
+        pub(crate) capacity: usize,
+        pub(crate) items: Vec<&'a T>,
+    }
+
+    /// Owned form read by `RingBufferQueue::deserialize`
+    #[derive(Deserialize)]
+    #[serde(rename = "RingBufferQueue", deny_unknown_fields)]
+    pub(crate) struct RingOwned<T> {
+        capacity: usize,
+        items: Vec<T>,
+    }
+
+    impl<T> RingOwned<T> {
+        /// Checks that the items fit a valid capacity (REQ-S2)
+        pub(crate) fn validate<E: serde::de::Error>(self) -> Result<(usize, Vec<T>), E> {
+            if self.capacity == 0 {
+                return Err(E::custom("capacity must be > 0"));
+            }
+            if self.items.len() > self.capacity {
+                return Err(E::custom(format!(
+                    "{} items do not fit in capacity {}",
+                    self.items.len(),
+                    self.capacity
+                )));
+            }
+            // Never reserve more than the input actually paid for
+            if self.capacity > MAX_DESERIALIZED_CAPACITY.max(self.items.len()) {
+                return Err(E::custom(format!(
+                    "capacity {} exceeds the limit of {} for {} items",
+                    self.capacity,
+                    MAX_DESERIALIZED_CAPACITY,
+                    self.items.len()
+                )));
+            }
+            Ok((self.capacity, self.items))
+        }
+    }
+
+    impl<T: Serialize> Serialize for RingBufferQueue<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let cap = self.capacity();
+            let items = (0..self.len)
+                .map(|i| self.buf[(self.head + i) % cap].as_ref().expect("slot inside len is occupied"))
+                .collect();
+            RingRef { capacity: cap, items }.serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for RingBufferQueue<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (capacity, items) = RingOwned::deserialize(deserializer)?.validate::<D::Error>()?;
+            let mut queue = RingBufferQueue::with_capacity(capacity);
+            for x in items {
+                // Cannot fail: the length was checked by `validate`
+                let _ = queue.enqueue(x);
+            }
+            Ok(queue)
+        }
+    }
+
+    impl<T: Serialize> Serialize for LinkedQueue<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            // `iter` is exact-size, so length-prefixed formats know the count up front
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedQueue<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let mut queue = LinkedQueue::new();
+            for x in Vec::<T>::deserialize(deserializer)? {
+                queue.enqueue(x);
+            }
+            Ok(queue)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,6 +23,7 @@
 //! - Compact codebase prioritizing readability
 //! - Basic documentation sufficient for understanding
 
+use crate::pool::NodePool;
 use std::ptr::NonNull;
 
 // ==========================
@@ -391,8 +392,8 @@ impl<T> RingBufferQueue<T> {
 /// A node in the linked queue containing an element and a pointer to the next node.
 ///
 /// Each node owns its successor through `Option<Box<Node<T>>>`, creating a chain
-/// of owned heap allocations. When a node is dropped, it automatically drops
-/// its successor, creating recursive cleanup.
+/// of owned heap allocations. Dropping a node would drop its successor
+/// recursively, so `LinkedQueue`'s own `Drop` unlinks the chain in a loop.
 ///
 /// # Memory Layout
 ///
@@ -440,7 +441,8 @@ struct Node<T> {
 /// # Memory Allocation Pattern
 ///
 /// Each enqueue allocates a new `Box<Node<T>>`. Each dequeue deallocates the head node.
-/// Memory usage grows and shrinks dynamically with queue size.
+/// Memory usage grows and shrinks dynamically with queue size. A queue created with
+/// [`with_pool`](LinkedQueue::with_pool) instead keeps dequeued nodes for later enqueues.
 ///
 /// # Examples
 ///
@@ -485,6 +487,8 @@ pub struct LinkedQueue<T> {
     tail: Option<NonNull<Node<T>>>,
     /// Current number of elements in the queue
     len: usize,
+    /// Spare node allocations (disabled unless created with `with_pool`)
+    pool: NodePool<Node<T>>,
 }
 
 impl<T> LinkedQueue<T> {
@@ -507,11 +511,40 @@ impl<T> LinkedQueue<T> {
     /// O(1) - no allocation or initialization required.
     #[inline] 
     pub fn new() -> Self { 
-        Self { 
-            head: None, 
-            tail: None, 
-            len: 0 
-        } 
+        Self::with_pool(0)
+    }
+
+    /// Creates an empty queue that recycles up to `limit` dequeued nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mission2::queue_alt::LinkedQueue;
+    ///
+    /// let mut queue = LinkedQueue::with_pool(8);
+    /// queue.enqueue(1);
+    /// queue.dequeue();
+    /// assert_eq!(queue.pooled(), 1); // Kept for the next enqueue
+    /// ```
+    #[inline]
+    pub fn with_pool(limit: usize) -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            pool: NodePool::new(limit),
+        }
+    }
+
+    /// Returns the number of spare nodes held by the pool.
+    #[inline]
+    pub fn pooled(&self) -> usize {
+        self.pool.len()
+    }
+
+    /// Frees every spare node held by the pool; the pool limit is kept.
+    pub fn shrink_pool(&mut self) {
+        self.pool.clear();
     }
     
     /// Returns the current number of elements in the queue.
@@ -590,7 +623,7 @@ impl<T> LinkedQueue<T> {
     ///
     /// # Implementation Details
     ///
-    /// Creates a new `Box<Node<T>>` and updates the tail pointer using unsafe code.
+    /// Creates (or takes from the pool) a `Box<Node<T>>` and updates the tail pointer using unsafe code.
     /// The unsafe block is carefully designed to maintain the safety invariants:
     /// - The tail pointer always refers to a node we own
     /// - The pointed-to node is always the actual tail of the list
@@ -606,7 +639,7 @@ impl<T> LinkedQueue<T> {
     ///
     /// O(1) - single heap allocation and pointer manipulation.
     pub fn enqueue(&mut self, x: T) {
-        let mut new = Box::new(Node { elem: x, next: None });
+        let mut new = self.pool.alloc(Node { elem: x, next: None });
         let new_ptr = Some(unsafe { NonNull::new_unchecked(&mut *new) });
 
         match self.tail {
@@ -675,7 +708,7 @@ impl<T> LinkedQueue<T> {
                 self.tail = None; // became empty - clear tail pointer
             }
             self.len -= 1;
-            old_head.elem
+            self.pool.release(old_head).elem
         })
     }
 
@@ -716,6 +749,17 @@ impl<T> LinkedQueue<T> {
     }
 }
 
+impl<T> Drop for LinkedQueue<T> {
+    /// Unlinks nodes one at a time instead of recursing through `next`
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut cur = self.head.take();
+        while let Some(mut node) = cur {
+            cur = node.next.take();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     //! # Unit Tests for Alternative Queue Implementations
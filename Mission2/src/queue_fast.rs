@@ -0,0 +1,569 @@
+//! Throughput-oriented ring buffer queue with uninitialized storage
+//!
+//! [`queue::RingBufferQueue`](crate::queue::RingBufferQueue) keeps every slot
+//! as an `Option<T>` and wraps indices with `%`. That is easy to prove safe,
+//! but each operation pays for the `Option` discriminant and an integer
+//! division. The `RingBufferQueue` in this module has the same public API
+//! and the same observable behavior, with a different layout:
+//!
+//! - slots are `MaybeUninit<T>`, so an element is exactly `size_of::<T>()` bytes
+//! - the buffer length is rounded up to a power of two and indices wrap with
+//!   `& mask` instead of `% capacity`
+//!
+//! Because the API is identical, switching is a one-line import change:
+//!
+//! ```rust
+//! use mission2::queue_fast::{RingBufferQueue, LinkedQueue};
+//!
+//! let mut ring = RingBufferQueue::with_capacity(3);
+//! ring.enqueue(1).unwrap();
+//! ring.enqueue(2).unwrap();
+//! assert_eq!(ring.dequeue(), Some(1));
+//!
+//! let mut linked = LinkedQueue::new();
+//! linked.enqueue("hello");
+//! assert_eq!(linked.dequeue(), Some("hello"));
+//! ```
+//!
+//! `LinkedQueue` has no per-slot overhead to remove, so it is re-exported
+//! unchanged from [`queue`](crate::queue).
+
+use crate::queue::drain_bounds;
+use std::fmt;
+use std::iter::{Chain, FusedIterator};
+use std::mem::MaybeUninit;
+use std::ops::RangeBounds;
+use std::{ptr, slice};
+
+pub use crate::queue::{LinkedIntoIter, LinkedIter, LinkedIterMut, LinkedQueue};
+
+/// A fixed-capacity FIFO ring buffer with power-of-two storage and bitmask indexing.
+///
+/// Drop-in replacement for [`queue::RingBufferQueue`](crate::queue::RingBufferQueue):
+/// the capacity passed to [`with_capacity`](RingBufferQueue::with_capacity) is
+/// still the exact limit enforced by `enqueue`. Only the backing buffer is
+/// rounded up to the next power of two, so a capacity of 1000 uses 1024 slots.
+///
+/// # Memory Layout
+///
+/// ```text
+/// with_capacity(3): 4 slots, mask = 0b11
+///   buf:  [c] [ ] [a] [b]      head = 2, len = 3
+///   slot of logical position i = (head + i) & mask
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use mission2::queue_fast::RingBufferQueue;
+///
+/// let mut queue = RingBufferQueue::with_capacity(3);
+/// assert!(queue.enqueue(1).is_ok());
+/// assert!(queue.enqueue(2).is_ok());
+/// assert!(queue.enqueue(3).is_ok());
+/// assert_eq!(queue.enqueue(4), Err(4)); // Capacity is 3, not the 4 slots
+/// assert_eq!(queue.dequeue(), Some(1));
+/// ```
+///
+/// # Requirements Satisfied
+/// - **REQ-R1**: Fixed capacity with backpressure via Err(value)
+/// - **REQ-R2**: Wrap-around with a bitmask, no division
+/// - **REQ-R3**: Contiguous storage, allocated once
+/// - **REQ-F1**: `MaybeUninit<T>` slots with no per-element tag
+/// - **REQ-F2**: Remaining elements are dropped exactly once
+pub struct RingBufferQueue<T> {
+    /// Slots `head .. head + len` (masked) are initialized, the rest are not
+    buf: Box<[MaybeUninit<T>]>,
+    /// `buf.len() - 1`; `buf.len()` is a power of two
+    mask: usize,
+    /// Logical capacity requested by the caller (`<= buf.len()`)
+    cap: usize,
+    /// Slot of the next element to dequeue
+    head: usize,
+    /// Current number of elements in the queue
+    len: usize,
+}
+
+impl<T> RingBufferQueue<T> {
+    /// Creates a new ring buffer queue with the specified capacity.
+    ///
+    /// # Panics
+    /// Panics if capacity is 0, or if the rounded-up buffer size overflows `usize`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::queue_fast::RingBufferQueue;
+    ///
+    /// let queue: RingBufferQueue<i32> = RingBufferQueue::with_capacity(10);
+    /// assert_eq!(queue.capacity(), 10);
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn with_capacity(cap: usize) -> Self {
+        assert!(cap > 0, "capacity must be > 0");
+        let slots = cap.checked_next_power_of_two().expect("capacity overflow");
+        Self {
+            buf: Box::new_uninit_slice(slots),
+            mask: slots - 1,
+            cap,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the queue.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the queue contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of elements, as passed to `with_capacity`.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns true if the queue is at maximum capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.cap
+    }
+
+    /// Maps a logical position (0 = front) to its slot in the buffer (REQ-R2).
+    #[inline]
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) & self.mask
+    }
+
+    /// Adds an element to the back of the queue, or returns it as `Err` if full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::queue_fast::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(2);
+    /// assert!(queue.enqueue(1).is_ok());
+    /// assert!(queue.enqueue(2).is_ok());
+    /// assert_eq!(queue.enqueue(3), Err(3)); // Full!
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G4**: O(1) complexity
+    /// - **REQ-R1**: Returns Err(value) when full (no reallocation)
+    #[inline]
+    pub fn enqueue(&mut self, x: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(x);
+        }
+        let at = self.slot(self.len);
+        self.buf[at].write(x);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element from the front of the queue.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::queue_fast::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(3);
+    /// queue.enqueue(1).unwrap();
+    /// queue.enqueue(2).unwrap();
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), Some(2));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G2**: Maintains FIFO ordering
+    /// - **REQ-G4**: O(1) complexity
+    #[inline]
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let at = self.head;
+        self.head = self.slot(1);
+        self.len -= 1;
+        // SAFETY: `at` was the front slot, so it is initialized; it is now
+        // outside the live range and will not be read again
+        Some(unsafe { self.buf[at].assume_init_read() })
+    }
+
+    /// Returns a reference to the front element without removing it.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            // SAFETY: the front slot is initialized when len > 0
+            Some(unsafe { self.buf[self.head].assume_init_ref() })
+        }
+    }
+
+    /// Adds an element to the back, evicting and returning the oldest one if full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::queue_fast::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(3);
+    /// assert_eq!(queue.enqueue_overwrite(1), None);
+    /// assert_eq!(queue.enqueue_overwrite(2), None);
+    /// assert_eq!(queue.enqueue_overwrite(3), None);
+    /// assert_eq!(queue.enqueue_overwrite(4), Some(1)); // Overwrote 1
+    /// assert_eq!(queue.dequeue(), Some(2));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-RO1**: Never fails
+    /// - **REQ-RO2**: Evicts the oldest element when at capacity
+    /// - **REQ-RO3**: Returns the evicted value
+    pub fn enqueue_overwrite(&mut self, x: T) -> Option<T> {
+        let old = if self.is_full() { self.dequeue() } else { None };
+        let at = self.slot(self.len);
+        self.buf[at].write(x);
+        self.len += 1;
+        old
+    }
+
+    /// `(start, len)` of the first occupied run and the length of the
+    /// wrapped-around run, which always starts at slot 0.
+    fn runs(&self) -> ((usize, usize), usize) {
+        let to_end = self.buf.len() - self.head;
+        if self.len <= to_end {
+            ((self.head, self.len), 0)
+        } else {
+            ((self.head, to_end), self.len - to_end)
+        }
+    }
+
+    /// The occupied slots as two slices in front-to-back order.
+    fn as_slices(&self) -> (&[T], &[T]) {
+        let ((start, first), second) = self.runs();
+        // SAFETY: both runs are inside the live range, and MaybeUninit<T>
+        // has the same layout as T
+        unsafe {
+            (
+                slice::from_raw_parts(self.buf.as_ptr().add(start).cast(), first),
+                slice::from_raw_parts(self.buf.as_ptr().cast(), second),
+            )
+        }
+    }
+
+    /// Mutable version of `as_slices`; the two runs never overlap.
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let ((start, first), second) = self.runs();
+        // SAFETY: as in `as_slices`; the second run ends before `head`
+        unsafe {
+            let base = self.buf.as_mut_ptr();
+            (
+                slice::from_raw_parts_mut(base.add(start).cast(), first),
+                slice::from_raw_parts_mut(base.cast(), second),
+            )
+        }
+    }
+
+    /// Returns an iterator over the elements from front to back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::queue_fast::RingBufferQueue;
+    ///
+    /// let mut queue = RingBufferQueue::with_capacity(2);
+    /// queue.enqueue(1).unwrap();
+    /// queue.enqueue(2).unwrap();
+    /// queue.dequeue();
+    /// queue.enqueue(3).unwrap(); // Wraps
+    /// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-G6**: Non-destructive front-to-back iteration
+    pub fn iter(&self) -> RingIter<'_, T> {
+        let (front, back) = self.as_slices();
+        RingIter { inner: front.iter().chain(back.iter()) }
+    }
+
+    /// Returns an iterator over mutable references, from front to back.
+    ///
+    /// # Requirements
+    /// - **REQ-G6**: Non-destructive front-to-back iteration
+    pub fn iter_mut(&mut self) -> RingIterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        RingIterMut { inner: front.iter_mut().chain(back.iter_mut()) }
+    }
+
+    /// Removes the elements in the logical `range` (0 = front) and returns them in order.
+    ///
+    /// Behaves like [`queue::RingBufferQueue::drain`](crate::queue::RingBufferQueue::drain):
+    /// the elements are moved out eagerly and the tail is shifted over the gap.
+    ///
+    /// # Panics
+    /// Panics if the range is decreasing or ends past `len()`.
+    ///
+    /// # Requirements
+    /// - **REQ-G7**: Range removal preserving the order of both parts
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::IntoIter<T> {
+        let (start, end) = drain_bounds(&range, self.len);
+        let removed = end - start;
+        let drained: Vec<T> = (start..end)
+            // SAFETY: every position in start..end is initialized and is
+            // overwritten or left outside the live range below
+            .map(|i| unsafe { self.buf[self.slot(i)].assume_init_read() })
+            .collect();
+        // Shift the elements after the range towards the front; no user code
+        // runs in this loop, so it cannot be interrupted half-way
+        for i in end..self.len {
+            let (from, to) = (self.slot(i), self.slot(i - removed));
+            // SAFETY: `from` is initialized, `to` was vacated above
+            unsafe { self.move_slot(from, to) };
+        }
+        self.len -= removed;
+        drained.into_iter()
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`, in their original order.
+    ///
+    /// # Requirements
+    /// - **REQ-G8**: Predicate-based removal preserving FIFO order
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        self.extract(|x| !keep(x), drop);
+    }
+
+    /// Removes the elements for which `pred` returns `true` and returns them front to back.
+    ///
+    /// # Requirements
+    /// - **REQ-G8**: Predicate-based removal preserving FIFO order
+    pub fn remove_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        self.extract(pred, |x| removed.push(x));
+        removed
+    }
+
+    /// Moves the element in slot `from` to the uninitialized slot `to`.
+    ///
+    /// # Safety
+    /// `from` must be initialized and `to` must not be (unless equal to `from`);
+    /// afterwards `from` is treated as uninitialized.
+    #[inline]
+    unsafe fn move_slot(&mut self, from: usize, to: usize) {
+        let base = self.buf.as_mut_ptr();
+        ptr::copy(base.add(from), base.add(to), 1);
+    }
+
+    /// Moves every element matching `remove` into `sink`, compacting the rest in place.
+    ///
+    /// Positions `write .. read` are holes while this runs. A guard closes
+    /// them even if `remove` or `sink` panics, so no element is dropped
+    /// twice or read after being moved out.
+    fn extract<F: FnMut(&T) -> bool, S: FnMut(T)>(&mut self, mut remove: F, mut sink: S) {
+        struct Compact<'a, T> {
+            queue: &'a mut RingBufferQueue<T>,
+            read: usize,
+            write: usize,
+            len: usize,
+        }
+
+        impl<T> Drop for Compact<'_, T> {
+            fn drop(&mut self) {
+                for i in self.read..self.len {
+                    let (from, to) = (self.queue.slot(i), self.queue.slot(self.write));
+                    // SAFETY: unvisited positions are initialized; `to` is a hole
+                    unsafe { self.queue.move_slot(from, to) };
+                    self.write += 1;
+                }
+                self.queue.len = self.write;
+            }
+        }
+
+        let len = self.len;
+        let mut g = Compact { queue: self, read: 0, write: 0, len };
+        while g.read < g.len {
+            let from = g.queue.slot(g.read);
+            // SAFETY: `read` is an unvisited, initialized position
+            let matched = remove(unsafe { g.queue.buf[from].assume_init_ref() });
+            if matched {
+                // SAFETY: as above; `read` advances before `sink` runs, so a
+                // panic in `sink` cannot make the guard touch this slot again
+                let x = unsafe { g.queue.buf[from].assume_init_read() };
+                g.read += 1;
+                sink(x);
+            } else {
+                let to = g.queue.slot(g.write);
+                // SAFETY: `from` is initialized; `to` is a hole or `from` itself
+                unsafe { g.queue.move_slot(from, to) };
+                g.read += 1;
+                g.write += 1;
+            }
+        }
+    }
+}
+
+impl<T> Drop for RingBufferQueue<T> {
+    /// Drops the remaining elements exactly once; vacant slots are never touched (REQ-F2)
+    fn drop(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back): (*mut [T], *mut [T]) = (front, back);
+        // SAFETY: both runs are initialized and never used again
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingBufferQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RingBufferQueue")
+            .field("items", &self.iter().collect::<Vec<_>>())
+            .field("capacity", &self.cap)
+            .finish()
+    }
+}
+
+// SAFETY: the queue owns its elements exactly like Vec<T> does
+unsafe impl<T: Send> Send for RingBufferQueue<T> {}
+unsafe impl<T: Sync> Sync for RingBufferQueue<T> {}
+
+/// Front-to-back iterator over a [`RingBufferQueue`], created by [`RingBufferQueue::iter`].
+#[derive(Debug, Clone)]
+pub struct RingIter<'a, T> {
+    inner: Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for RingIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for RingIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for RingIter<'_, T> {}
+impl<T> FusedIterator for RingIter<'_, T> {}
+
+/// Front-to-back iterator over mutable references, created by [`RingBufferQueue::iter_mut`].
+#[derive(Debug)]
+pub struct RingIterMut<'a, T> {
+    inner: Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>,
+}
+
+impl<'a, T> Iterator for RingIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for RingIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for RingIterMut<'_, T> {}
+impl<T> FusedIterator for RingIterMut<'_, T> {}
+
+/// Owning front-to-back iterator, created by `into_iter()` on a [`RingBufferQueue`].
+#[derive(Debug)]
+pub struct RingIntoIter<T> {
+    queue: RingBufferQueue<T>,
+}
+
+impl<T> Iterator for RingIntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len(), Some(self.queue.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for RingIntoIter<T> {}
+impl<T> FusedIterator for RingIntoIter<T> {}
+
+impl<T> IntoIterator for RingBufferQueue<T> {
+    type Item = T;
+    type IntoIter = RingIntoIter<T>;
+
+    /// Consumes the queue, yielding its elements from front to back (REQ-G6)
+    fn into_iter(self) -> RingIntoIter<T> {
+        RingIntoIter { queue: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingBufferQueue<T> {
+    type Item = &'a T;
+    type IntoIter = RingIter<'a, T>;
+
+    fn into_iter(self) -> RingIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut RingBufferQueue<T> {
+    type Item = &'a mut T;
+    type IntoIter = RingIterMut<'a, T>;
+
+    fn into_iter(self) -> RingIterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// Same wire format as `queue::RingBufferQueue`, so the two are interchangeable (REQ-S1, REQ-S2)
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::RingBufferQueue;
+    use crate::queue::serde_impls::{RingOwned, RingRef};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T: Serialize> Serialize for RingBufferQueue<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            RingRef { capacity: self.cap, items: self.iter().collect() }.serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for RingBufferQueue<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (capacity, items) = RingOwned::deserialize(deserializer)?.validate::<D::Error>()?;
+            if capacity.checked_next_power_of_two().is_none() {
+                return Err(D::Error::custom(format!("capacity {} overflows the buffer size", capacity)));
+            }
+            let mut queue = RingBufferQueue::with_capacity(capacity);
+            for x in items {
+                // Cannot fail: the length was checked by `validate`
+                let _ = queue.enqueue(x);
+            }
+            Ok(queue)
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! Unrolled linked queue: a linked list of fixed-size blocks
+//!
+//! [`LinkedQueue`](crate::LinkedQueue) allocates one node per element and
+//! follows a pointer for every dequeue. `UnrolledQueue` links blocks of
+//! [`BLOCK_LEN`] slots instead: elements inside a block sit next to each
+//! other like in a ring buffer, and the allocator is only involved once per
+//! block. Growth is still unbounded, as with `LinkedQueue`.
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+use std::slice;
+
+/// Number of element slots per block
+pub const BLOCK_LEN: usize = 64;
+
+/// A fixed-size array of slots plus the link to the next block.
+struct Block<T> {
+    /// Which slots are initialized is tracked by the queue, not the block
+    slots: [MaybeUninit<T>; BLOCK_LEN],
+    /// Ownership of the next block (None for the tail block)
+    next: Option<Box<Block<T>>>,
+}
+
+impl<T> Block<T> {
+    fn new() -> Box<Self> {
+        Box::new(Block { slots: [const { MaybeUninit::uninit() }; BLOCK_LEN], next: None })
+    }
+}
+
+/// An unbounded FIFO queue built from linked blocks of [`BLOCK_LEN`] elements.
+///
+/// # Memory Layout
+///
+/// ```text
+///            front                                   back
+///              v                                       v
+/// head -> [ . . a b c d ] -> [ e f g h i j ] -> [ k l . . . . ] <- tail
+///          consumed          full block           free slots
+/// ```
+/// Only the head block has consumed slots at the front and only the tail
+/// block has free slots at the back; every block in between is full.
+/// When the head block is used up it is kept as a spare for the next block
+/// the tail needs, so a queue that hovers around a block boundary does not
+/// allocate on every crossing.
+///
+/// # Examples
+///
+/// ```rust
+/// use mission2::UnrolledQueue;
+///
+/// let mut queue = UnrolledQueue::new();
+/// for i in 0..1000 {
+///     queue.enqueue(i);
+/// }
+/// assert_eq!(queue.len(), 1000);
+/// assert_eq!(queue.block_count(), 16); // 1000 elements in blocks of 64
+///
+/// assert_eq!(queue.dequeue(), Some(0));
+/// assert_eq!(queue.peek(), Some(&1));
+/// ```
+///
+/// # Performance
+///
+/// - **enqueue**: O(1) - write into the tail block; one allocation per `BLOCK_LEN` elements
+/// - **dequeue**: O(1) - read from the head block; a pointer hop per `BLOCK_LEN` elements
+/// - **peek**: O(1)
+/// - **Space**: Dynamic, at most two partially used blocks plus one spare
+///
+/// # Requirements Satisfied
+/// - **REQ-U1**: Unbounded growth through linked fixed-size blocks
+/// - **REQ-U2**: One allocation per block, with a spare block reused
+/// - **REQ-U3**: `LinkedQueue`'s FIFO and iteration API; iterative drop
+pub struct UnrolledQueue<T> {
+    /// Ownership of the first block (None until the first enqueue)
+    head: Option<Box<Block<T>>>,
+    /// Raw pointer to the last block (for O(1) enqueue)
+    tail: Option<NonNull<Block<T>>>,
+    /// Slot of the front element in the head block
+    front: usize,
+    /// Slot the next element is written to in the tail block
+    back: usize,
+    /// Current number of elements in the queue
+    len: usize,
+    /// A used-up block kept for reuse
+    spare: Option<Box<Block<T>>>,
+}
+
+impl<T> UnrolledQueue<T> {
+    /// Creates a new empty queue; the first block is allocated on the first enqueue.
+    #[inline]
+    pub fn new() -> Self {
+        Self { head: None, tail: None, front: 0, back: 0, len: 0, spare: None }
+    }
+
+    /// Returns the current number of elements in the queue.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the queue contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of blocks linked into the queue (not counting the spare).
+    pub fn block_count(&self) -> usize {
+        let mut count = 0;
+        let mut block = self.head.as_deref();
+        while let Some(b) = block {
+            count += 1;
+            block = b.next.as_deref();
+        }
+        count
+    }
+
+    /// Adds an element to the back of the queue.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::UnrolledQueue;
+    ///
+    /// let mut queue = UnrolledQueue::new();
+    /// queue.enqueue("a");
+    /// queue.enqueue("b");
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-U1**: Never fails; a new block is linked when the tail block is full
+    /// - **REQ-U2**: Allocates at most once per `BLOCK_LEN` elements
+    pub fn enqueue(&mut self, x: T) {
+        let mut tail = match self.tail {
+            Some(tail) if self.back < BLOCK_LEN => tail,
+            _ => self.push_block(),
+        };
+        // SAFETY: `tail` points into a block owned by the `head` chain, and
+        // slot `back` of the tail block is free
+        unsafe { tail.as_mut().slots[self.back].write(x) };
+        self.back += 1;
+        self.len += 1;
+    }
+
+    /// Removes and returns the element from the front of the queue.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::UnrolledQueue;
+    ///
+    /// let mut queue = UnrolledQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), Some(2));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-U3**: FIFO order
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let head = self.head.as_mut().expect("non-empty queue has a head block");
+        // SAFETY: slot `front` of the head block holds the front element; it
+        // is outside the live range once `front` advances
+        let x = unsafe { head.slots[self.front].assume_init_read() };
+        self.front += 1;
+        self.len -= 1;
+
+        if self.len == 0 {
+            // Keep the single remaining block and start over at slot 0
+            self.front = 0;
+            self.back = 0;
+        } else if self.front == BLOCK_LEN {
+            // Head block used up; the rest lives in later blocks
+            let mut used = self.head.take().expect("checked above");
+            self.head = used.next.take();
+            self.front = 0;
+            self.spare = Some(used);
+        }
+        Some(x)
+    }
+
+    /// Returns a reference to the front element without removing it.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+        let head = self.head.as_ref().expect("non-empty queue has a head block");
+        // SAFETY: slot `front` holds the front element
+        Some(unsafe { head.slots[self.front].assume_init_ref() })
+    }
+
+    /// Returns an iterator over the elements from front to back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mission2::UnrolledQueue;
+    ///
+    /// let mut queue = UnrolledQueue::new();
+    /// for i in 0..100 {
+    ///     queue.enqueue(i);
+    /// }
+    /// queue.dequeue();
+    /// assert!(queue.iter().copied().eq(1..100));
+    /// ```
+    ///
+    /// # Requirements
+    /// - **REQ-U3**: Non-destructive front-to-back iteration
+    pub fn iter(&self) -> UnrolledIter<'_, T> {
+        UnrolledIter { run: [].iter(), next: self.head.as_deref(), start: self.front, remaining: self.len }
+    }
+
+    /// Returns an iterator over mutable references, from front to back.
+    ///
+    /// # Requirements
+    /// - **REQ-U3**: Non-destructive front-to-back iteration
+    pub fn iter_mut(&mut self) -> UnrolledIterMut<'_, T> {
+        UnrolledIterMut {
+            run: [].iter_mut(),
+            next: self.head.as_deref_mut(),
+            start: self.front,
+            remaining: self.len,
+        }
+    }
+
+    /// Links an empty block after the tail (or as the head) and returns it.
+    #[cold]
+    fn push_block(&mut self) -> NonNull<Block<T>> {
+        let mut block = self.spare.take().unwrap_or_else(Block::new);
+        let ptr = NonNull::from(&mut *block);
+        match self.tail {
+            // SAFETY: the tail pointer refers to the last block of `head`'s chain
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(block) },
+            None => self.head = Some(block),
+        }
+        self.tail = Some(ptr);
+        self.back = 0;
+        ptr
+    }
+}
+
+impl<T> Drop for UnrolledQueue<T> {
+    /// Drops the remaining elements, then frees the blocks without recursion (REQ-U3)
+    fn drop(&mut self) {
+        for x in self.iter_mut() {
+            // SAFETY: every live element is visited exactly once and the
+            // queue is never used again
+            unsafe { std::ptr::drop_in_place(x) };
+        }
+        self.tail = None;
+        let mut cur = self.head.take();
+        while let Some(mut block) = cur {
+            cur = block.next.take();
+        }
+    }
+}
+
+impl<T> Default for UnrolledQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UnrolledQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnrolledQueue")
+            .field("items", &self.iter().collect::<Vec<_>>())
+            .field("blocks", &self.block_count())
+            .finish()
+    }
+}
+
+// SAFETY: the queue owns its elements and blocks; the tail pointer never escapes
+unsafe impl<T: Send> Send for UnrolledQueue<T> {}
+unsafe impl<T: Sync> Sync for UnrolledQueue<T> {}
+
+/// Length of the live run in a block whose first live slot is `start`
+#[inline]
+fn run_len(start: usize, remaining: usize) -> usize {
+    remaining.min(BLOCK_LEN - start)
+}
+
+/// Front-to-back iterator over an [`UnrolledQueue`], created by [`UnrolledQueue::iter`].
+pub struct UnrolledIter<'a, T> {
+    /// Live elements of the current block not yet yielded
+    run: slice::Iter<'a, T>,
+    /// Block to continue with once `run` is exhausted
+    next: Option<&'a Block<T>>,
+    /// First live slot of `next` (only the head block starts past 0)
+    start: usize,
+    /// Elements left in the blocks from `next` on
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for UnrolledIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if let Some(x) = self.run.next() {
+            return Some(x);
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+        let block = self.next.take()?;
+        let n = run_len(self.start, self.remaining);
+        // SAFETY: the `n` slots from `start` are live elements of the queue
+        let run = unsafe { slice::from_raw_parts(block.slots.as_ptr().add(self.start).cast::<T>(), n) };
+        self.run = run.iter();
+        self.next = block.next.as_deref();
+        self.start = 0;
+        self.remaining -= n;
+        self.run.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.run.len() + self.remaining;
+        (n, Some(n))
+    }
+}
+
+impl<T> Clone for UnrolledIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { run: self.run.clone(), next: self.next, start: self.start, remaining: self.remaining }
+    }
+}
+
+impl<T> ExactSizeIterator for UnrolledIter<'_, T> {}
+impl<T> FusedIterator for UnrolledIter<'_, T> {}
+
+/// Front-to-back iterator over mutable references, created by [`UnrolledQueue::iter_mut`].
+pub struct UnrolledIterMut<'a, T> {
+    run: slice::IterMut<'a, T>,
+    next: Option<&'a mut Block<T>>,
+    start: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for UnrolledIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if let Some(x) = self.run.next() {
+            return Some(x);
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+        let Block { slots, next } = self.next.take()?;
+        let n = run_len(self.start, self.remaining);
+        // SAFETY: as in `UnrolledIter::next`; `slots` and `next` are disjoint borrows
+        let run = unsafe { slice::from_raw_parts_mut(slots.as_mut_ptr().add(self.start).cast::<T>(), n) };
+        self.run = run.iter_mut();
+        self.next = next.as_deref_mut();
+        self.start = 0;
+        self.remaining -= n;
+        self.run.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.run.len() + self.remaining;
+        (n, Some(n))
+    }
+}
+
+impl<T> ExactSizeIterator for UnrolledIterMut<'_, T> {}
+impl<T> FusedIterator for UnrolledIterMut<'_, T> {}
+
+/// Owning front-to-back iterator, created by `into_iter()` on an [`UnrolledQueue`].
+pub struct UnrolledIntoIter<T> {
+    queue: UnrolledQueue<T>,
+}
+
+impl<T> Iterator for UnrolledIntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len(), Some(self.queue.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for UnrolledIntoIter<T> {}
+impl<T> FusedIterator for UnrolledIntoIter<T> {}
+
+impl<T> IntoIterator for UnrolledQueue<T> {
+    type Item = T;
+    type IntoIter = UnrolledIntoIter<T>;
+
+    fn into_iter(self) -> UnrolledIntoIter<T> {
+        UnrolledIntoIter { queue: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a UnrolledQueue<T> {
+    type Item = &'a T;
+    type IntoIter = UnrolledIter<'a, T>;
+
+    fn into_iter(self) -> UnrolledIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut UnrolledQueue<T> {
+    type Item = &'a mut T;
+    type IntoIter = UnrolledIterMut<'a, T>;
+
+    fn into_iter(self) -> UnrolledIterMut<'a, T> {
+        self.iter_mut()
+    }
+}
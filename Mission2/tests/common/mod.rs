@@ -0,0 +1,25 @@
+// Helpers shared by the integration tests. Each test binary compiles this
+// module separately and uses only part of it.
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Adds one to the shared counter when dropped, to check each element drops exactly once
+pub struct Counted(pub Rc<Cell<usize>>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+/// Runs `f` on a thread whose stack is far too small for one frame per node
+pub fn on_small_stack<F: FnOnce() + Send + 'static>(f: F) {
+    std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
@@ -0,0 +1,151 @@
+use mission2::RingDeque;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+mod common;
+use common::Counted;
+
+/// Builds a deque of `cap` slots whose front sits at slot `offset`, holding `items`
+fn deque_at_offset(cap: usize, offset: usize, items: &[i32]) -> RingDeque<i32> {
+    let mut d = RingDeque::with_capacity(cap);
+    for _ in 0..offset {
+        d.push_back(0);
+        d.pop_front();
+    }
+    d.extend(items.iter().copied());
+    assert_eq!(d.capacity(), cap);
+    d
+}
+
+/// REQ-D1, REQ-D2: Random pushes/pops at both ends match VecDeque through many growths
+#[test]
+fn deque_randomized_matches_vecdeque() {
+    let mut d = RingDeque::new();
+    let mut v = VecDeque::new();
+
+    for i in 0..20_000u32 {
+        let x = i as i32;
+        match i.wrapping_mul(2654435761u32) % 9 {
+            0..=2 => {
+                d.push_back(x);
+                v.push_back(x);
+            }
+            3..=5 => {
+                d.push_front(x);
+                v.push_front(x);
+            }
+            6 => assert_eq!(d.pop_front(), v.pop_front()),
+            7 => assert_eq!(d.pop_back(), v.pop_back()),
+            _ => {
+                if let Some(back) = d.back_mut() {
+                    *back += 1;
+                    *v.back_mut().unwrap() += 1;
+                }
+            }
+        }
+        assert_eq!(d.len(), v.len());
+        assert_eq!(d.front(), v.front());
+        assert_eq!(d.back(), v.back());
+    }
+    assert!(d.capacity().is_power_of_two());
+    assert!(d.iter().eq(v.iter()));
+    assert!(d.iter().rev().eq(v.iter().rev()));
+}
+
+/// REQ-D1: Growing a full, wrapped buffer keeps logical order whichever run is moved
+#[test]
+fn deque_growth_preserves_order_across_wrap() {
+    for offset in 0..8 {
+        let items: Vec<i32> = (1..=8).collect();
+        let mut d = deque_at_offset(8, offset, &items);
+
+        d.push_back(9); // full: doubles to 16
+        d.push_front(0);
+        assert_eq!(d.capacity(), 16, "offset {}", offset);
+        assert!(d.iter().copied().eq(0..=9), "offset {}", offset);
+        for (i, expected) in (0..=9).enumerate() {
+            assert_eq!(d[i], expected);
+        }
+    }
+}
+
+/// REQ-D3: Indexing by logical position and out-of-bounds behavior
+#[test]
+fn deque_indexing() {
+    let mut d = deque_at_offset(4, 3, &[10, 20, 30]);
+    assert_eq!(d[0], 10);
+    assert_eq!(d[2], 30);
+    d[1] += 5;
+    assert_eq!(d.get(1), Some(&25));
+    assert_eq!(d.get(3), None);
+    *d.get_mut(0).unwrap() = 11;
+    *d.front_mut().unwrap() += 1;
+    assert_eq!(d.iter().copied().collect::<Vec<_>>(), vec![12, 25, 30]);
+
+    let empty: RingDeque<i32> = RingDeque::new();
+    assert_eq!(empty.front(), None);
+    assert_eq!(empty.back(), None);
+    assert_eq!(empty.get(0), None);
+}
+
+/// REQ-D3: Index panics past the end, like VecDeque
+#[test]
+#[should_panic(expected = "index 3 out of bounds for length 3")]
+fn deque_index_past_len_panics() {
+    let d: RingDeque<i32> = (0..3).collect();
+    let _ = d[3];
+}
+
+/// REQ-D4: as_slices splits at the wrap; make_contiguous joins in place without allocating
+#[test]
+fn deque_slices_and_make_contiguous() {
+    for offset in 0..8 {
+        let mut d = deque_at_offset(8, offset, &[1, 2, 3, 4, 5]);
+        let (a, b) = d.as_slices();
+        assert_eq!(a.len(), 5.min(8 - offset), "offset {}", offset);
+        assert_eq!([a, b].concat(), vec![1, 2, 3, 4, 5]);
+
+        let (a, b) = d.as_mut_slices();
+        a.iter_mut().chain(b.iter_mut()).for_each(|x| *x *= 10);
+
+        assert_eq!(d.make_contiguous(), &[10, 20, 30, 40, 50]);
+        assert_eq!(d.as_slices().1, &[] as &[i32]);
+        assert_eq!(d.capacity(), 8);
+
+        // Still a working ring afterwards
+        d.push_front(0);
+        d.push_back(60);
+        assert!(d.iter().copied().eq((0..=6).map(|x| x * 10)));
+    }
+}
+
+/// REQ-G3: Every element is dropped exactly once: on pop, clear, or when the deque drops
+#[test]
+fn deque_drops_each_element_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut d = RingDeque::with_capacity(2);
+    for i in 0..100 {
+        if i % 2 == 0 {
+            d.push_back(Counted(Rc::clone(&drops)));
+        } else {
+            d.push_front(Counted(Rc::clone(&drops)));
+        }
+    }
+    drop(d.pop_front());
+    drop(d.pop_back());
+    assert_eq!(drops.get(), 2);
+
+    d.make_contiguous();
+    assert_eq!(drops.get(), 2);
+    d.clear();
+    assert_eq!(drops.get(), 100);
+    assert!(d.is_empty());
+
+    for _ in 0..10 {
+        d.push_front(Counted(Rc::clone(&drops)));
+    }
+    drop(d);
+    assert_eq!(drops.get(), 110);
+    assert_eq!(Rc::strong_count(&drops), 1);
+}
@@ -4,6 +4,9 @@
 use mission2::queue_alt::{RingBufferQueue, LinkedQueue};
 use std::collections::VecDeque;
 
+mod common;
+use common::on_small_stack;
+
 #[test] // REQ-R1, REQ-G2, REQ-G3, REQ-G4
 fn ring_basic_wrap_and_full() {
     let mut q = RingBufferQueue::with_capacity(3);
@@ -282,4 +285,38 @@ fn determinism_test() {
         // Linked queue should have all values
         assert_eq!(linked_order, sequence.to_vec());
     }
-}
\ No newline at end of file
+}
+
+#[test] // Dropping a very long queue must not recurse once per node
+fn linked_drop_long_queue_iteratively() {
+    on_small_stack(|| {
+        let mut q = LinkedQueue::new();
+        for i in 0..1_000_000u32 {
+            q.enqueue(i);
+        }
+        drop(q);
+    });
+}
+
+#[test] // Node pool: dequeued nodes are reused, FIFO order is unchanged
+fn linked_pool_recycles_nodes() {
+    let mut q = LinkedQueue::with_pool(3);
+    let mut v = VecDeque::new();
+    for round in 0..100 {
+        for i in 0..5 {
+            q.enqueue(round * 5 + i);
+            v.push_back(round * 5 + i);
+        }
+        for _ in 0..5 {
+            assert_eq!(q.dequeue(), v.pop_front());
+        }
+        assert_eq!(q.pooled(), 3);
+    }
+    assert!(q.is_empty());
+
+    q.shrink_pool();
+    assert_eq!(q.pooled(), 0);
+    q.enqueue(1);
+    assert_eq!(q.dequeue(), Some(1));
+    assert_eq!(q.pooled(), 1); // The limit survives the shrink
+}
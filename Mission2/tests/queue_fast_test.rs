@@ -0,0 +1,72 @@
+// Runs the whole queue_test.rs suite against queue_fast, so the two ring
+// buffers are held to exactly the same behavior (REQ-F2)
+
+mod fast {
+    /// Shadows the crate name so queue_test.rs's `use mission2::queue::...`
+    /// resolves to the fast implementation
+    mod mission2 {
+        pub use ::mission2::queue_fast as queue;
+    }
+
+    include!("queue_test.rs");
+
+    // queue_test.rs already loads tests/common; reuse it rather than load it twice
+    pub(crate) use common::Counted;
+}
+
+use mission2::queue_fast::RingBufferQueue;
+use std::cell::Cell;
+use std::rc::Rc;
+use fast::Counted;
+
+/// REQ-F1: Capacity stays exact although the buffer is rounded up to a power of two
+#[test]
+fn fast_ring_capacity_is_exact() {
+    for cap in [1, 3, 5, 8, 1000] {
+        let mut q = RingBufferQueue::with_capacity(cap);
+        for i in 0..cap {
+            assert!(q.enqueue(i).is_ok());
+        }
+        assert!(q.is_full());
+        assert_eq!(q.enqueue(cap), Err(cap));
+        // Keep cycling so head visits the padding slots past `cap`
+        for i in 0..3 * cap {
+            assert_eq!(q.enqueue_overwrite(cap + i), Some(i));
+        }
+        assert!(q.iter().copied().eq(3 * cap..4 * cap));
+    }
+}
+
+/// REQ-F2: Elements still queued at drop time are dropped exactly once, wherever they sit
+#[test]
+fn fast_ring_drops_remaining_elements_once() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut q = RingBufferQueue::with_capacity(5);
+        for _ in 0..5 {
+            q.enqueue(Counted(Rc::clone(&drops))).ok().unwrap();
+        }
+        // Wrap around, evicting two and dequeuing one
+        drop(q.enqueue_overwrite(Counted(Rc::clone(&drops))));
+        drop(q.enqueue_overwrite(Counted(Rc::clone(&drops))));
+        drop(q.dequeue());
+        assert_eq!(drops.get(), 3);
+
+        drop(q.drain(1..2));
+        q.retain(|_| true);
+        assert_eq!(drops.get(), 4);
+        assert_eq!(q.len(), 3);
+    }
+    assert_eq!(drops.get(), 7);
+    assert_eq!(Rc::strong_count(&drops), 1);
+
+    // A partially consumed owning iterator drops the rest
+    let mut q = RingBufferQueue::with_capacity(4);
+    for _ in 0..4 {
+        q.enqueue(Counted(Rc::clone(&drops))).ok().unwrap();
+    }
+    let mut it = q.into_iter();
+    drop(it.next());
+    drop(it);
+    assert_eq!(drops.get(), 11);
+}
@@ -1,6 +1,11 @@
 use mission2::queue::{RingBufferQueue, LinkedQueue};
 use std::collections::VecDeque;
 
+// An explicit path, so the module still resolves when queue_fast_test.rs includes this file
+#[path = "common/mod.rs"]
+mod common;
+use common::on_small_stack;
+
 /// REQ-R1, REQ-G2, REQ-G3, REQ-G4: Ring buffer wrap-around and capacity tests
 #[test]
 fn ring_basic_wrap_and_full() {
@@ -468,4 +473,229 @@ fn stress_alternating_patterns() {
     assert!(linked.is_empty());
     assert!(ring_sync.is_empty());
     assert!(linked_sync.is_empty());
-}
\ No newline at end of file
+}
+
+/// Builds a ring of `cap` slots whose front sits at slot `offset`, holding `items`
+fn ring_at_offset(cap: usize, offset: usize, items: &[i32]) -> RingBufferQueue<i32> {
+    let mut q = RingBufferQueue::with_capacity(cap);
+    for _ in 0..offset {
+        q.enqueue(-1).unwrap();
+        q.dequeue();
+    }
+    for &x in items {
+        q.enqueue(x).unwrap();
+    }
+    q
+}
+
+/// REQ-G6: Iterators visit the wrapped-around segment in FIFO order
+#[test]
+fn ring_iterators_cover_wrapped_segment() {
+    let items: Vec<i32> = (10..15).collect();
+    for offset in 0..6 {
+        let mut q = ring_at_offset(6, offset, &items);
+        assert_eq!(q.iter().copied().collect::<Vec<_>>(), items, "offset {}", offset);
+        assert_eq!(q.iter().rev().copied().collect::<Vec<_>>(), items.iter().rev().copied().collect::<Vec<_>>());
+        assert_eq!(q.iter().len(), 5);
+
+        for x in &mut q {
+            *x *= 2;
+        }
+        assert_eq!(q.peek(), Some(&20));
+        let doubled: Vec<i32> = q.into_iter().collect();
+        assert_eq!(doubled, vec![20, 22, 24, 26, 28]);
+    }
+
+    // Full ring with head in the middle: both segments are non-empty
+    let q = ring_at_offset(4, 3, &[1, 2, 3, 4]);
+    assert!(q.is_full());
+    assert_eq!((&q).into_iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+}
+
+/// REQ-G7, REQ-G8: drain/retain/remove_if match VecDeque at every wrap position
+#[test]
+fn ring_drain_and_retain_match_vecdeque() {
+    let items: Vec<i32> = (0..7).collect();
+    for offset in 0..8 {
+        for start in 0..=7 {
+            for end in start..=7 {
+                let mut q = ring_at_offset(8, offset, &items);
+                let mut model: VecDeque<i32> = items.iter().copied().collect();
+                let drained: Vec<i32> = q.drain(start..end).collect();
+                assert_eq!(drained, model.drain(start..end).collect::<Vec<_>>());
+                // The freed slots are usable and the order is intact
+                while q.enqueue(99).is_ok() {
+                    model.push_back(99);
+                }
+                assert_eq!(q.into_iter().collect::<Vec<_>>(), Vec::from(model));
+            }
+        }
+
+        let mut q = ring_at_offset(8, offset, &items);
+        q.retain(|x| x % 3 != 0);
+        assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4, 5]);
+        assert_eq!(q.remove_if(|x| *x > 3), vec![4, 5]);
+        assert_eq!(q.drain(..).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(q.is_empty());
+        assert!(q.enqueue(7).is_ok());
+        assert_eq!(q.peek(), Some(&7));
+    }
+}
+
+/// REQ-G3, REQ-G8: A panicking predicate leaves the ring consistent
+#[test]
+fn ring_retain_survives_panicking_predicate() {
+    let mut q = ring_at_offset(5, 3, &[1, 2, 3, 4, 5]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        q.retain(|&x| {
+            assert!(x != 4, "predicate failure");
+            x % 2 == 1
+        })
+    }));
+    assert!(result.is_err());
+    // 2 was removed before the panic; nothing else is lost
+    assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![1, 3, 4, 5]);
+    assert!(q.enqueue(6).is_ok());
+    assert_eq!(q.into_iter().collect::<Vec<_>>(), vec![1, 3, 4, 5, 6]);
+}
+
+/// REQ-G7: drain panics on an out-of-bounds range, like Vec::drain
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn ring_drain_rejects_range_past_len() {
+    let mut q = ring_at_offset(4, 1, &[1, 2]);
+    q.drain(1..3);
+}
+
+/// REQ-G6, REQ-G7: Linked queue iterators and drain keep head/tail consistent
+#[test]
+fn linked_iterators_and_drain() {
+    let mut q = LinkedQueue::new();
+    for i in 0..6 {
+        q.enqueue(i);
+    }
+    assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(q.iter().len(), 6);
+    q.iter_mut().for_each(|x| *x *= 10);
+    assert_eq!(q.drain(1..=2).collect::<Vec<_>>(), vec![10, 20]);
+    assert_eq!(q.drain(..1).collect::<Vec<_>>(), vec![0]);
+    assert_eq!(q.drain(2..).collect::<Vec<_>>(), vec![50]); // Removes the tail
+    assert_eq!(q.len(), 2);
+    q.enqueue(60);
+    assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![30, 40, 60]);
+
+    let _ = q.drain(..); // Dropping the iterator unconsumed still removes the range
+    assert!(q.is_empty());
+    q.enqueue(1);
+    assert_eq!(q.into_iter().collect::<Vec<_>>(), vec![1]);
+}
+
+/// REQ-G8: Linked queue retain/remove_if match VecDeque, including tail removal
+#[test]
+fn linked_retain_and_remove_if_match_vecdeque() {
+    for mask in 0u32..64 {
+        let mut q = LinkedQueue::new();
+        let mut model = VecDeque::new();
+        for i in 0..6 {
+            q.enqueue(i);
+            model.push_back(i);
+        }
+        let removed = q.remove_if(|&x| mask & (1 << x) != 0);
+        let expected_removed: Vec<u32> = model.iter().copied().filter(|&x| mask & (1 << x) != 0).collect();
+        model.retain(|&x| mask & (1 << x) == 0);
+        assert_eq!(removed, expected_removed);
+
+        // Enqueue after removal exercises the recomputed tail
+        q.enqueue(100);
+        model.push_back(100);
+        q.retain(|&x| x != 0);
+        model.retain(|&x| x != 0);
+        assert_eq!(q.len(), model.len());
+        assert_eq!(q.into_iter().collect::<Vec<_>>(), Vec::from(model));
+    }
+}
+
+/// REQ-L4: Dropping a very long queue does not recurse through the nodes
+#[test]
+fn linked_drop_long_queue_iteratively() {
+    on_small_stack(|| {
+        let mut q = LinkedQueue::new();
+        for i in 0..1_000_000u32 {
+            q.enqueue(i);
+        }
+        drop(q);
+
+        // Live nodes and a full pool of spare ones
+        let mut q = LinkedQueue::with_pool(usize::MAX);
+        for i in 0..200_000u32 {
+            q.enqueue(i);
+        }
+        for _ in 0..100_000 {
+            q.dequeue();
+        }
+        assert_eq!(q.pooled(), 100_000);
+        drop(q);
+    });
+}
+
+/// REQ-L5: The node pool recycles up to its limit without changing FIFO behavior
+#[test]
+fn linked_pool_recycles_nodes() {
+    let mut q = LinkedQueue::with_pool(4);
+    let mut model = VecDeque::new();
+    for i in 0..10 {
+        q.enqueue(i);
+        model.push_back(i);
+    }
+    for _ in 0..6 {
+        assert_eq!(q.dequeue(), model.pop_front());
+    }
+    assert_eq!(q.pooled(), 4); // Capped at the limit
+
+    q.enqueue(10);
+    model.push_back(10);
+    assert_eq!(q.pooled(), 3);
+
+    // Removed nodes go back to the pool too, and tail stays correct
+    q.retain(|x| x % 2 == 0);
+    model.retain(|x| x % 2 == 0);
+    assert_eq!(q.pooled(), 4);
+    q.enqueue(12);
+    model.push_back(12);
+    assert!(q.iter().eq(model.iter()));
+
+    q.shrink_pool();
+    assert_eq!(q.pooled(), 0);
+    assert_eq!(q.into_iter().collect::<Vec<_>>(), Vec::from(model));
+
+    // Pooling is off by default
+    let mut plain = LinkedQueue::new();
+    plain.enqueue(1);
+    plain.dequeue();
+    assert_eq!(plain.pooled(), 0);
+}
+
+/// REQ-G3, REQ-L5: Pooled nodes never keep or double-drop an element
+#[test]
+fn linked_pool_drops_elements_once() {
+    let token = std::rc::Rc::new(());
+    let mut q = LinkedQueue::with_pool(16);
+    for _ in 0..3 {
+        for _ in 0..10 {
+            q.enqueue(std::rc::Rc::clone(&token));
+        }
+        for _ in 0..7 {
+            drop(q.dequeue());
+        }
+        drop(q.remove_if(|_| true));
+    }
+    assert_eq!(std::rc::Rc::strong_count(&token), 1);
+    assert_eq!(q.pooled(), 10);
+
+    for _ in 0..25 {
+        q.enqueue(std::rc::Rc::clone(&token));
+    }
+    assert_eq!(std::rc::Rc::strong_count(&token), 26);
+    drop(q);
+    assert_eq!(std::rc::Rc::strong_count(&token), 1);
+}
@@ -0,0 +1,110 @@
+// JSON round trips for both queues; run with `cargo test --features serde`
+#![cfg(feature = "serde")]
+
+use mission2::queue::{LinkedQueue, RingBufferQueue, MAX_DESERIALIZED_CAPACITY};
+
+/// REQ-S1: capacity and logical order survive, whatever head/tail were
+#[test]
+fn ring_json_round_trip_after_wrap() {
+    let mut q = RingBufferQueue::with_capacity(4);
+    for i in 0..4 {
+        q.enqueue(i).unwrap();
+    }
+    q.dequeue();
+    q.dequeue();
+    q.enqueue(4).unwrap(); // Wraps: physical order is [4, _, 2, 3]
+
+    let json = serde_json::to_string(&q).unwrap();
+    assert_eq!(json, r#"{"capacity":4,"items":[2,3,4]}"#);
+
+    let mut back: RingBufferQueue<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.capacity(), 4);
+    assert_eq!(back.len(), 3);
+    assert!(back.enqueue(5).is_ok());
+    assert_eq!(back.enqueue(6), Err(6)); // Still bounded by the saved capacity
+    for expected in 2..=5 {
+        assert_eq!(back.dequeue(), Some(expected));
+    }
+    assert!(back.is_empty());
+}
+
+/// REQ-S1: overwrite mode keeps working after a reload
+#[test]
+fn ring_json_round_trip_of_overwritten_buffer() {
+    let mut q = RingBufferQueue::with_capacity(2);
+    for word in ["a", "b", "c"] {
+        q.enqueue_overwrite(word.to_string());
+    }
+    let json = serde_json::to_string(&q).unwrap();
+    let mut back: RingBufferQueue<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.enqueue_overwrite("d".to_string()), Some("b".to_string()));
+    assert_eq!(back.dequeue().as_deref(), Some("c"));
+    assert_eq!(back.dequeue().as_deref(), Some("d"));
+}
+
+/// REQ-S2: invalid input is an error, not a panic
+#[test]
+fn ring_json_rejects_invalid_capacity() {
+    assert!(serde_json::from_str::<RingBufferQueue<u8>>(r#"{"capacity":0,"items":[]}"#).is_err());
+    assert!(serde_json::from_str::<RingBufferQueue<u8>>(r#"{"capacity":1,"items":[1,2]}"#).is_err());
+    assert!(serde_json::from_str::<RingBufferQueue<u8>>(r#"{"capacity":1,"items":[],"head":0}"#).is_err());
+    assert!(serde_json::from_str::<RingBufferQueue<u8>>(r#"[1,2]"#).is_err());
+}
+
+/// REQ-S2: the wire capacity cannot trigger a panic or a huge allocation
+#[test]
+fn ring_json_rejects_oversized_capacity() {
+    type Fast<T> = mission2::queue_fast::RingBufferQueue<T>;
+    for cap in [usize::MAX, 1 << 40, MAX_DESERIALIZED_CAPACITY + 1] {
+        let json = format!(r#"{{"capacity":{},"items":[]}}"#, cap);
+        let err = serde_json::from_str::<RingBufferQueue<u64>>(&json).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"), "{}", err);
+        assert!(serde_json::from_str::<Fast<u64>>(&json).is_err());
+    }
+
+    let json = format!(r#"{{"capacity":{},"items":[7]}}"#, MAX_DESERIALIZED_CAPACITY);
+    let q: Fast<u8> = serde_json::from_str(&json).unwrap();
+    assert_eq!((q.capacity(), q.len()), (MAX_DESERIALIZED_CAPACITY, 1));
+}
+
+/// REQ-S1: linked queue is a plain front-to-back sequence
+#[test]
+fn linked_json_round_trip() {
+    let mut q = LinkedQueue::new();
+    for i in 0..5 {
+        q.enqueue((i, format!("job-{}", i)));
+    }
+    q.dequeue();
+    let json = serde_json::to_string(&q).unwrap();
+    assert!(json.starts_with(r#"[[1,"job-1"],[2,"job-2"]"#));
+
+    let mut back: LinkedQueue<(u32, String)> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.len(), 4);
+    back.enqueue((9, "job-9".to_string()));
+    let order: Vec<u32> = std::iter::from_fn(|| back.dequeue()).map(|(i, _)| i).collect();
+    assert_eq!(order, vec![1, 2, 3, 4, 9]);
+
+    let empty: LinkedQueue<u8> = serde_json::from_str("[]").unwrap();
+    assert!(empty.is_empty());
+}
+
+/// REQ-S1, REQ-S2, REQ-F2: queue_fast uses the same format, so saved rings load into either type
+#[test]
+fn fast_ring_shares_the_json_format() {
+    let mut q = mission2::queue_fast::RingBufferQueue::with_capacity(3);
+    for i in 0..5 {
+        q.enqueue_overwrite(i);
+    }
+    let json = serde_json::to_string(&q).unwrap();
+    assert_eq!(json, r#"{"capacity":3,"items":[2,3,4]}"#);
+
+    let mut slow: RingBufferQueue<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(slow.capacity(), 3);
+    assert_eq!(slow.dequeue(), Some(2));
+    assert_eq!(serde_json::to_string(&slow).unwrap(), r#"{"capacity":3,"items":[3,4]}"#);
+
+    let bad = serde_json::from_str::<mission2::queue_fast::RingBufferQueue<i32>>(
+        r#"{"capacity":1,"items":[1,2]}"#,
+    );
+    assert!(bad.unwrap_err().to_string().contains("do not fit"));
+}
@@ -0,0 +1,80 @@
+use mission2::unrolled::{UnrolledQueue, BLOCK_LEN};
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+mod common;
+use common::{on_small_stack, Counted};
+
+/// REQ-U1, REQ-U3: Behavior matches VecDeque across many block boundaries
+#[test]
+fn unrolled_matches_vecdeque() {
+    let mut q = UnrolledQueue::new();
+    let mut v = VecDeque::new();
+
+    for i in 0..20_000u32 {
+        if i.wrapping_mul(2654435761u32) % 5 < 3 {
+            q.enqueue(i);
+            v.push_back(i);
+        } else {
+            assert_eq!(q.dequeue(), v.pop_front());
+        }
+        assert_eq!(q.len(), v.len());
+        assert_eq!(q.peek(), v.front());
+    }
+    assert!(q.iter().eq(v.iter()));
+    for x in q.iter_mut() {
+        *x += 1;
+    }
+    assert!(q.into_iter().eq(v.into_iter().map(|x| x + 1)));
+}
+
+/// REQ-U2: One block per BLOCK_LEN elements, and the spare block absorbs boundary crossings
+#[test]
+fn unrolled_allocates_per_block() {
+    let mut q = UnrolledQueue::new();
+    assert_eq!(q.block_count(), 0);
+    for i in 0..10 * BLOCK_LEN {
+        q.enqueue(i);
+    }
+    assert_eq!(q.block_count(), 10);
+    assert_eq!(q.iter().len(), 10 * BLOCK_LEN);
+
+    // Sliding window: the queue moves through many blocks at constant length
+    for i in 0..100 * BLOCK_LEN {
+        assert_eq!(q.dequeue(), Some(i));
+        q.enqueue(10 * BLOCK_LEN + i);
+        assert!(q.block_count() <= 11);
+    }
+
+    while q.dequeue().is_some() {}
+    assert!(q.is_empty());
+    assert_eq!(q.block_count(), 1); // The last block is kept for reuse
+    q.enqueue(7);
+    assert_eq!(q.peek(), Some(&7));
+}
+
+/// REQ-G3, REQ-U3: Remaining elements are dropped exactly once, and long queues drop iteratively
+#[test]
+fn unrolled_drops_elements_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut q = UnrolledQueue::new();
+    for _ in 0..3 * BLOCK_LEN + 5 {
+        q.enqueue(Counted(Rc::clone(&drops)));
+    }
+    for _ in 0..BLOCK_LEN + 2 {
+        drop(q.dequeue());
+    }
+    assert_eq!(drops.get(), BLOCK_LEN + 2);
+    drop(q);
+    assert_eq!(drops.get(), 3 * BLOCK_LEN + 5);
+    assert_eq!(Rc::strong_count(&drops), 1);
+
+    on_small_stack(|| {
+        let mut q = UnrolledQueue::new();
+        for i in 0..10_000_000u32 {
+            q.enqueue(i);
+        }
+        drop(q);
+    });
+}